@@ -73,6 +73,21 @@ pub enum DataIntegrityError {
     RemoteResponseFormatError(String),
     #[error("Indexing error in remote call {0}")]
     RemoteIndexingError(String),
+
+    #[error("Unknown action type {0}")]
+    UnknownAction(String),
+    #[error("Incompatible quantity units: {0}")]
+    UnitMismatch(String),
+    #[error("Required internal parameter {0} was not provided")]
+    MissingInternalParameter(String),
+    #[error("Insufficient inventory: {0}")]
+    InsufficientInventory(String),
+    #[error("Invalid record: {0}")]
+    InvalidRecord(String),
+    #[error("Record cannot be deleted: {0}")]
+    UndeletableRecord(String),
+    #[error("{0}. Records written before the failure could not be rolled back: {1}")]
+    IncompleteRollback(Box<DataIntegrityError>, String),
 }
 
 pub type RecordAPIResult<T> = Result<T, DataIntegrityError>;
@@ -108,4 +123,6 @@ pub mod identifiers {
     // Holochain DHT storage type IDs
     pub const RECORD_INITIAL_ENTRY_LINK_TAG: &'static [u8] = b"initial_entry";
     pub const RECORD_IDENTITY_ANCHOR_LINK_TAG: &'static [u8] = b"identity_anchor";
+    pub const RECORD_IDEMPOTENCY_KEY_PATH_SEGMENT: &'static [u8] = b"idempotency_key";
+    pub const RECORD_IDEMPOTENCY_KEY_LINK_TAG: &'static [u8] = b"idempotent_record";
 }
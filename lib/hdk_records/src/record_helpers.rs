@@ -26,6 +26,7 @@ use crate::{
         read_entry_identity,
         calculate_identity_address,
     },
+    link_helpers::get_linked_addresses,
 };
 
 /// Helper to retrieve the HeaderHash for an Element
@@ -125,6 +126,67 @@ pub fn read_record_entry<T, R, B, S>(
     read_record_entry_by_identity::<T, R, B>(&identity_address)
 }
 
+/// Represent a client-supplied idempotency key for some entry type as a `Path`.
+///
+/// Rooted separately from the entry type's own `Path` so that keys are not
+/// enumerated alongside record identities by `query_root_index`.
+///
+fn idempotency_key_path_for<S>(
+    entry_def_id: S,
+    idempotency_key: &String,
+) -> Path
+    where S: AsRef<str>,
+{
+    Path::from(vec![
+        crate::identifiers::RECORD_IDEMPOTENCY_KEY_PATH_SEGMENT.to_vec().into(),
+        entry_def_id.as_ref().as_bytes().to_vec().into(),
+        idempotency_key.as_bytes().to_vec().into(),
+    ])
+}
+
+/// Determine whether a record has already been created with the given client-supplied
+/// idempotency key, and return it if so.
+///
+/// Records created without a key are never considered duplicates. Callers with side-effects
+/// of record creation should check this before performing them, so that retried requests are
+/// not applied twice; and should register the key with `create_idempotency_key_index` once the
+/// record has been created.
+///
+/// :WARNING: this is a check-then-create pattern, not a uniqueness constraint. Requests with the
+/// same key which run concurrently (on one agent's device before either has committed, or on
+/// different agents' devices before their writes have propagated) will not see each other, and
+/// will each create a record. Keys protect against a client retrying a request whose response was
+/// lost, not against parallel submissions.
+///
+pub fn read_existing_record<I, R, B, S>(
+    entry_def_id: S,
+    idempotency_key: &Option<String>,
+) -> RecordAPIResult<Option<(RevisionHash, B, I)>>
+    where S: AsRef<str>,
+        I: std::fmt::Debug,
+        B: DnaAddressable<EntryHash>,
+        Entry: TryFrom<R>,
+        R: std::fmt::Debug + Identified<I, B>,
+        SerializedBytes: TryInto<R, Error = SerializedBytesError>,
+{
+    let key = match idempotency_key {
+        Some(key) => key,
+        None => return Ok(None),
+    };
+    let key_address = idempotency_key_path_for(&entry_def_id, key).path_entry_hash()?;
+    let identity_address = match get_linked_addresses(&key_address, LinkTag::new(crate::identifiers::RECORD_IDEMPOTENCY_KEY_LINK_TAG))?.pop() {
+        Some(address) => address,
+        None => return Ok(None),
+    };
+
+    match read_record_entry_by_identity::<I, R, B>(&identity_address) {
+        Ok(existing) => Ok(Some(existing)),
+        // no identity index, or the record was since deleted
+        Err(DataIntegrityError::IndexNotFound(_)) | Err(DataIntegrityError::EntryNotFound) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 //-------------------------------[ CREATE ]-------------------------------------
 
 /// Creates a new record in the DHT, assigns it an identity index (@see identity_helpers.rs)
@@ -160,6 +222,33 @@ pub fn create_record<I, R: Clone, B, C, E, S>(
     Ok((header_hash, identity, entry_data))
 }
 
+/// Register a newly created record against the client-supplied idempotency key it was created with,
+/// so that subsequent requests with the same key resolve to it. @see `read_existing_record`
+///
+pub fn create_idempotency_key_index<B, S>(
+    entry_def_id: S,
+    idempotency_key: &Option<String>,
+    identity: &B,
+) -> RecordAPIResult<()>
+    where S: AsRef<str>,
+        B: DnaAddressable<EntryHash>,
+{
+    let key = match idempotency_key {
+        Some(key) => key,
+        None => return Ok(()),
+    };
+    let key_path = idempotency_key_path_for(&entry_def_id, key);
+    key_path.ensure()?;
+
+    create_link(
+        key_path.path_entry_hash()?,
+        calculate_identity_address(&entry_def_id, identity)?,
+        LinkTag::new(crate::identifiers::RECORD_IDEMPOTENCY_KEY_LINK_TAG),
+    )?;
+
+    Ok(())
+}
+
 //-------------------------------[ UPDATE ]-------------------------------------
 
 /// Updates a record in the DHT by its `HeaderHash` (revision ID)
@@ -11,20 +11,30 @@ use super::{
     Action,
     ActionEffect,
     ProcessType,
+    LocationEffect,
+    ContainedEffect,
+    StageEffect,
+    StateEffect,
 };
 
 // setup for core actions as in-memory statics
 
 macro_rules! generate_builtin_actions {
-    ($key: expr; $( $a:ident => $e:expr, $f:expr, $g:expr );*) => {
+    ($key: expr; $( $a:ident => $e:expr, $on:expr, $acc:expr, $f:expr, $g:expr, $loc:expr, $cont:expr, $stg:expr, $st:expr );*) => {
         match &str::replace($key, "-", "_")[..] {
             $(
                 stringify!($a) => Some(Action {
                     id: str::replace(stringify!($a), "_", "-"),
                     label: str::replace(stringify!($a), "_", "-"),
                     resource_effect: $e,
+                    onhand_effect: $on,
+                    accounting_effect: $acc,
                     input_output: $f,
                     pairs_with: stringify!($g).to_string(),
+                    location_effect: $loc,
+                    contained_effect: $cont,
+                    stage_effect: $stg,
+                    state_effect: $st,
                 })
             ),*,
             _ => None,
@@ -32,27 +42,51 @@ macro_rules! generate_builtin_actions {
     }
 }
 
+// :NOTE: columns are `resourceEffect, onhandEffect, accountingEffect, inputOutput, pairsWith,
+//        locationEffect, containedEffect, stageEffect, stateEffect`
 pub fn get_builtin_action(key: &str) -> Option<Action> {
     generate_builtin_actions!(
         key;
-        dropoff => ActionEffect::Increment, ProcessType::Output, pickup;
-        pickup => ActionEffect::Decrement, ProcessType::Input, dropoff;
-        consume => ActionEffect::Decrement, ProcessType::Input, notApplicable;
-        use => ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        work => ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        cite => ActionEffect::NoEffect, ProcessType::Input, notApplicable;
-        produce => ActionEffect::Increment, ProcessType::Output, notApplicable;
-        accept => ActionEffect::NoEffect, ProcessType::Input, modify;
-        modify => ActionEffect::NoEffect, ProcessType::Output, accept;
-        pass => ActionEffect::NoEffect, ProcessType::Output, accept;
-        fail => ActionEffect::NoEffect, ProcessType::Output, accept;
-        deliver_service => ActionEffect::NoEffect, ProcessType::Output, notApplicable;
-        transfer_all_rights => ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        transfer_custody => ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        transfer => ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        move => ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable;
-        raise => ActionEffect::Increment, ProcessType::NotApplicable, notApplicable;
-        lower => ActionEffect::Decrement, ProcessType::NotApplicable, notApplicable
+        dropoff => ActionEffect::Increment, ActionEffect::Increment, ActionEffect::Increment, ProcessType::Output, pickup,
+            LocationEffect::Update, ContainedEffect::NoEffect, StageEffect::Update, StateEffect::NoEffect;
+        pickup => ActionEffect::Decrement, ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::Input, dropoff,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        consume => ActionEffect::Decrement, ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::Input, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        use => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        work => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        cite => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        produce => ActionEffect::Increment, ActionEffect::Increment, ActionEffect::Increment, ProcessType::Output, notApplicable,
+            LocationEffect::New, ContainedEffect::NoEffect, StageEffect::Update, StateEffect::NoEffect;
+        accept => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, modify,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        modify => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, accept,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::Update, StateEffect::NoEffect;
+        pass => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, accept,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::Update, StateEffect::Update;
+        fail => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, accept,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::Update, StateEffect::Update;
+        deliver_service => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        transfer_all_rights => ActionEffect::DecrementIncrement, ActionEffect::NoEffect, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        transfer_custody => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ActionEffect::NoEffect, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::UpdateTo, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        transfer => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::UpdateTo, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        move => ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ActionEffect::DecrementIncrement, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::Update, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        raise => ActionEffect::Increment, ActionEffect::Increment, ActionEffect::Increment, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        lower => ActionEffect::Decrement, ActionEffect::Decrement, ActionEffect::Decrement, ProcessType::NotApplicable, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::NoEffect, StageEffect::NoEffect, StateEffect::NoEffect;
+        combine => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Input, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::Update, StageEffect::NoEffect, StateEffect::NoEffect;
+        separate => ActionEffect::NoEffect, ActionEffect::NoEffect, ActionEffect::NoEffect, ProcessType::Output, notApplicable,
+            LocationEffect::NoEffect, ContainedEffect::Remove, StageEffect::Update, StateEffect::NoEffect
     )
 }
 
@@ -76,6 +110,8 @@ pub fn get_all_builtin_actions() -> Vec<Action> {
         get_builtin_action("move").unwrap(),
         get_builtin_action("raise").unwrap(),
         get_builtin_action("lower").unwrap(),
+        get_builtin_action("combine").unwrap(),
+        get_builtin_action("separate").unwrap(),
     ]
 }
 
@@ -89,10 +125,27 @@ mod tests {
             id: "consume".to_string(),
             label: "consume".to_string(),
             resource_effect: ActionEffect::Decrement,
+            onhand_effect: ActionEffect::Decrement,
+            accounting_effect: ActionEffect::Decrement,
             input_output: ProcessType::Input,
             pairs_with: "notApplicable".to_string(),
+            location_effect: LocationEffect::NoEffect,
+            contained_effect: ContainedEffect::NoEffect,
+            stage_effect: StageEffect::NoEffect,
+            state_effect: StateEffect::NoEffect,
         };
 
         assert_eq!(get_builtin_action("consume").unwrap(), action);
     }
+
+    #[test]
+    fn test_transfer_effects_are_split() {
+        let custody = get_builtin_action("transfer-custody").unwrap();
+        assert_eq!(custody.onhand_effect, ActionEffect::DecrementIncrement);
+        assert_eq!(custody.accounting_effect, ActionEffect::NoEffect);
+
+        let rights = get_builtin_action("transfer-all-rights").unwrap();
+        assert_eq!(rights.onhand_effect, ActionEffect::NoEffect);
+        assert_eq!(rights.accounting_effect, ActionEffect::DecrementIncrement);
+    }
 }
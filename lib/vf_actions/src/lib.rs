@@ -7,7 +7,7 @@
 use serde::{Deserializer, Serializer, de::Error};
 
 use hdk::prelude::*;
-use vf_attributes_hdk::{ ActionId, ProcessAddress, EconomicResourceAddress };
+use vf_attributes_hdk::{ ActionId, EconomicResourceAddress };
 
 pub mod builtins;
 pub use builtins::{ get_builtin_action, get_all_builtin_actions };
@@ -60,6 +60,45 @@ pub enum ActionInventoryEffect {
     Decrement,
 }
 
+/// Effect of an action on the `currentLocation` of affected EconomicResources.
+/// `update` applies to every inventory the event touches; `updateTo` applies only
+/// to the receiving inventory; `new` applies only when an inventory is created by the event.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LocationEffect {
+    NoEffect,
+    New,
+    Update,
+    UpdateTo,
+}
+
+/// Effect of an action on the `containedIn` relationship of affected EconomicResources
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ContainedEffect {
+    NoEffect,
+    Update,
+    Remove,
+}
+
+/// Effect of an action on the `stage` of affected EconomicResources
+/// (the ProcessSpecification of the Process the event is an output of)
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum StageEffect {
+    NoEffect,
+    Update,
+}
+
+/// Effect of an action on the `state` of affected EconomicResources
+/// (the action ID of the most recent state-altering event)
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum StateEffect {
+    NoEffect,
+    Update,
+}
+
 #[derive(SerializedBytes, Debug, Clone, Copy, PartialEq)]
 pub enum ProcessType {
     NotApplicable,
@@ -101,24 +140,33 @@ pub struct Action {
     pub id: String,
     pub label: String,
     pub resource_effect: ActionEffect,
+    pub onhand_effect: ActionEffect,
+    pub accounting_effect: ActionEffect,
     pub input_output: ProcessType,
     pub pairs_with: String, // any of the action labels, or "notApplicable"
+    pub location_effect: LocationEffect,
+    pub contained_effect: ContainedEffect,
+    pub stage_effect: StageEffect,
+    pub state_effect: StateEffect,
 }
 
 /**
- * Validation for EconomicEvent, Commitment and Process to ensure correct use of actions & Processes
+ * Validation for EconomicEvent, Commitment, Intent and RecipeFlow to ensure correct use of actions & Processes
+ *
+ * Generic over the process identifier so that recipe flows can be checked against their `RecipeProcess`.
+ * `record_type` names the kind of record being validated in any error messages.
  */
-pub fn validate_flow_action(action_id: ActionId, input_process: Option<ProcessAddress>, output_process: Option<ProcessAddress>) -> Result<(), String> {
+pub fn validate_flow_action<P>(record_type: &str, action_id: ActionId, input_process: Option<P>, output_process: Option<P>) -> Result<(), String> {
     if let Some(action) = get_builtin_action(action_id.as_ref()) {
         match action.input_output {
             ProcessType::NotApplicable => if input_process.is_some() || output_process.is_some() {
-                Err(format!("EconomicEvent of '{:}' action cannot link to processes", action.id).into())
+                Err(format!("{} of '{:}' action cannot link to processes", record_type, action.id).into())
             } else { Ok(()) },
             ProcessType::Input => if input_process.is_none() {
-                Err(format!("EconomicEvent input process required for '{:}' action", action.id).into())
+                Err(format!("{} input process required for '{:}' action", record_type, action.id).into())
             } else { Ok(()) },
             ProcessType::Output => if output_process.is_none() {
-                Err(format!("EconomicEvent output process required for '{:}' action", action.id).into())
+                Err(format!("{} output process required for '{:}' action", record_type, action.id).into())
             } else { Ok(()) },
         }
     } else {
@@ -126,6 +174,12 @@ pub fn validate_flow_action(action_id: ActionId, input_process: Option<ProcessAd
     }
 }
 
+/// Whether a flow of the given action records (or plans) `work` performed by its provider
+pub fn is_work(action_id: &ActionId) -> bool {
+    let action_id: &String = action_id.as_ref();
+    action_id == "work"
+}
+
 pub fn validate_move_inventories(resouce_inventoried_as: Option<EconomicResourceAddress>, to_resource_inventoried_as: Option<EconomicResourceAddress>) -> Result<(), String> {
     match resouce_inventoried_as {
         Some(_) => match to_resource_inventoried_as {
@@ -138,3 +192,12 @@ pub fn validate_move_inventories(resouce_inventoried_as: Option<EconomicResource
         },
     }
 }
+
+pub fn validate_container_inventories(action_id: &str, resource_inventoried_as: Option<EconomicResourceAddress>, to_resource_inventoried_as: Option<EconomicResourceAddress>) -> Result<(), String> {
+    match (resource_inventoried_as, to_resource_inventoried_as) {
+        (Some(contained), Some(container)) => if contained == container {
+            Err(format!("{} EconomicEvent cannot use the same resource as both the contained resource and its container", action_id))
+        } else { Ok(()) },
+        _ => Err(format!("{} EconomicEvent requires both the contained resource and the container resource (toResourceInventoriedAs)", action_id)),
+    }
+}
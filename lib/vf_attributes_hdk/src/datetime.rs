@@ -0,0 +1,83 @@
+/**
+ * Date & time representation shared by all ValueFlows record types
+ *
+ * @package Holo-REA
+ */
+use std::fmt;
+use chrono::{ DateTime, Duration, FixedOffset, TimeZone, Utc };
+use serde::{ Serialize, Deserialize, Serializer, Deserializer };
+use holochain_zome_types::timestamp::Timestamp;
+
+/// A point in time, as provided by the author of a record.
+///
+/// Retains the UTC offset which the time was expressed in, whilst comparing and ordering
+/// by the absolute instant so that values from different record types can be compared
+/// directly. Serializes as an RFC3339 string.
+///
+/// Also reads the integer `Timestamp` values which `Process` and `Proposal` records were
+/// previously stored with, so that existing entries migrate transparently on read and
+/// are written in the current format upon their next update.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeValue(pub DateTime<FixedOffset>);
+
+impl DateTimeValue {
+    /// The point in time the given number of seconds earlier, expressed in the same UTC offset
+    pub fn seconds_before(&self, seconds: f64) -> Self {
+        Self(self.0 - Duration::milliseconds((seconds * 1000.0).round() as i64))
+    }
+}
+
+/// Formats accepted when reading stored dates
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredDateTime {
+    DateTime(DateTime<FixedOffset>),
+    // :NOTE: legacy format for `Process` & `Proposal`, which has no offset and is read as UTC
+    Timestamp(Timestamp),
+}
+
+impl Serialize for DateTimeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTimeValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>,
+    {
+        Ok(match StoredDateTime::deserialize(deserializer)? {
+            StoredDateTime::DateTime(value) => Self(value),
+            StoredDateTime::Timestamp(value) => Self::from(value),
+        })
+    }
+}
+
+impl From<DateTime<FixedOffset>> for DateTimeValue {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Timestamp> for DateTimeValue {
+    fn from(value: Timestamp) -> Self {
+        let micros = value.as_micros();
+        let utc = Utc.timestamp(micros.div_euclid(1_000_000), (micros.rem_euclid(1_000_000) * 1_000) as u32);
+        Self(utc.into())
+    }
+}
+
+impl From<DateTimeValue> for DateTime<FixedOffset> {
+    fn from(value: DateTimeValue) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for DateTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_rfc3339())
+    }
+}
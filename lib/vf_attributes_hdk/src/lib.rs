@@ -1,8 +1,11 @@
 use hdk_type_serialization_macros::*;
 
+mod datetime;
+pub use datetime::DateTimeValue;
+
 // re-exports for convenience
 pub use chrono::{ FixedOffset, Utc, DateTime };
-pub use holo_hash::{ AgentPubKey, EntryHash, HeaderHash };
+pub use holo_hash::{ AgentPubKey, DnaHash, EntryHash, HeaderHash };
 pub use holochain_zome_types::timestamp::Timestamp;
 pub use hdk_type_serialization_macros::{RevisionHash, DnaAddressable};
 pub use hdk_semantic_indexes_zome_rpc::{ByHeader, ByAddress};
@@ -16,7 +19,100 @@ addressable_identifier!(LocationAddress => EntryHash);
 dna_scoped_string!(UnitId);
 addressable_identifier!(UnitInternalAddress => EntryHash);
 
-addressable_identifier!(AgentAddress => AgentPubKey);
+// Agents are `Person` or `Organization` profile records in the agent DNA, either of which
+// may act as the `provider` or `receiver` of a flow. Persons are additionally bound to an `AgentPubKey`.
+//
+// Records written before agent profiles existed reference agents directly by `AgentPubKey`.
+// These remain readable, and are resolved to the agent's `Person` profile (if any) via `whois`.
+// The key is held as an `EntryHash` over the same raw bytes (so index `Path`s built from it are
+// unchanged) and is written back out in its original form. Identifiers read back from indexes
+// carry no record of which kind they were and are always returned as `Profile`, so equality
+// ignores the variant.
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+#[serde(from = "AgentAddressSerial", into = "AgentAddressSerial")]
+pub enum AgentAddress {
+    Profile(DnaHash, EntryHash),
+    AgentKey(DnaHash, EntryHash),
+}
+
+impl AgentAddress {
+    /// The key this identifier references an agent by, if it predates agent profiles
+    pub fn agent_pub_key(&self) -> Option<AgentPubKey> {
+        match self {
+            AgentAddress::Profile(_, _) => None,
+            AgentAddress::AgentKey(_, hash) => Some(hash.to_owned().retype(holo_hash::hash_type::Agent)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum AgentAddressSerial {
+    Profile(DnaHash, EntryHash),
+    AgentKey(DnaHash, AgentPubKey),
+}
+
+impl From<AgentAddressSerial> for AgentAddress {
+    fn from(v: AgentAddressSerial) -> AgentAddress {
+        match v {
+            AgentAddressSerial::Profile(dna, hash) => AgentAddress::Profile(dna, hash),
+            AgentAddressSerial::AgentKey(dna, key) => AgentAddress::AgentKey(dna, key.retype(holo_hash::hash_type::Entry)),
+        }
+    }
+}
+
+impl From<AgentAddress> for AgentAddressSerial {
+    fn from(v: AgentAddress) -> AgentAddressSerial {
+        match v {
+            AgentAddress::Profile(dna, hash) => AgentAddressSerial::Profile(dna, hash),
+            AgentAddress::AgentKey(dna, hash) => AgentAddressSerial::AgentKey(dna, hash.retype(holo_hash::hash_type::Agent)),
+        }
+    }
+}
+
+impl PartialEq for AgentAddress {
+    fn eq(&self, other: &Self) -> bool {
+        AsRef::<DnaHash>::as_ref(self) == AsRef::<DnaHash>::as_ref(other)
+            && AsRef::<EntryHash>::as_ref(self) == AsRef::<EntryHash>::as_ref(other)
+    }
+}
+
+impl Eq for AgentAddress {}
+
+impl std::hash::Hash for AgentAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        AsRef::<DnaHash>::as_ref(self).hash(state);
+        AsRef::<EntryHash>::as_ref(self).hash(state);
+    }
+}
+
+impl DnaAddressable<EntryHash> for AgentAddress {
+    fn new(dna: DnaHash, identifier: EntryHash) -> Self {
+        Self::Profile(dna, identifier)
+    }
+}
+
+impl AsRef<EntryHash> for AgentAddress {
+    fn as_ref(&self) -> &EntryHash {
+        match self {
+            AgentAddress::Profile(_, hash) | AgentAddress::AgentKey(_, hash) => hash,
+        }
+    }
+}
+
+impl AsRef<DnaHash> for AgentAddress {
+    fn as_ref(&self) -> &DnaHash {
+        match self {
+            AgentAddress::Profile(dna, _) | AgentAddress::AgentKey(dna, _) => dna,
+        }
+    }
+}
+
+impl Into<Vec<u8>> for AgentAddress {
+    fn into(self) -> Vec<u8> {
+        extern_id_to_bytes::<Self, EntryHash>(&self)
+    }
+}
 
 addressable_identifier!(EconomicEventAddress => EntryHash);
 addressable_identifier!(EconomicResourceAddress => EntryHash);
@@ -27,19 +123,41 @@ addressable_identifier!(CommitmentAddress => EntryHash);
 addressable_identifier!(FulfillmentAddress => EntryHash);
 addressable_identifier!(IntentAddress => EntryHash);
 addressable_identifier!(SatisfactionAddress => EntryHash);
+addressable_identifier!(ClaimAddress => EntryHash);
+addressable_identifier!(SettlementAddress => EntryHash);
+addressable_identifier!(AppreciationAddress => EntryHash);
 
 addressable_identifier!(PlanAddress => EntryHash);
+addressable_identifier!(ScenarioAddress => EntryHash);
 addressable_identifier!(AgreementAddress => EntryHash);
 
 addressable_identifier!(ResourceSpecificationAddress => EntryHash);
 addressable_identifier!(ProcessSpecificationAddress => EntryHash);
 
+addressable_identifier!(RecipeResourceAddress => EntryHash);
+addressable_identifier!(RecipeProcessAddress => EntryHash);
+addressable_identifier!(RecipeFlowAddress => EntryHash);
+addressable_identifier!(RecipeExchangeAddress => EntryHash);
+
 addressable_identifier!(ProposedIntentAddress => EntryHash);
 addressable_identifier!(ProposalAddress => EntryHash);
 addressable_identifier!(ProposedToAddress => EntryHash);
 
 addressable_identifier!(EventOrCommitmentAddress => EntryHash);
 
+/// Ensure that a pair of optional dates occur in the expected order, where both are provided
+///
+pub fn validate_date_order<T: PartialOrd>(
+    earlier: &Option<T>, earlier_field: &str,
+    later: &Option<T>, later_field: &str,
+) -> Result<(), String> {
+    match (earlier, later) {
+        (Some(earlier), Some(later)) if earlier > later =>
+            Err(format!("{} must not be later than {}", earlier_field, later_field)),
+        _ => Ok(()),
+    }
+}
+
 impl From<EventOrCommitmentAddress> for CommitmentAddress {
     fn from(a: EventOrCommitmentAddress) -> Self {
         Self(a.0, a.1)
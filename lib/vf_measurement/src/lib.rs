@@ -34,24 +34,46 @@ impl<'a> QuantityValue {
     pub fn get_unit(&'a self) -> Option<UnitId> {
         self.has_unit.to_owned()
     }
+
+    pub fn get_numerical_value(&'a self) -> f64 {
+        self.has_numerical_value
+    }
+}
+
+/// Error returned when performing arithmetic on quantities of differing units
+#[derive(Clone, PartialEq, Debug)]
+pub struct UnitMismatch {
+    pub expected: Option<UnitId>,
+    pub found: Option<UnitId>,
 }
 
-pub fn add(q1: QuantityValue, q2: QuantityValue) -> QuantityValue {
-    if q1.has_unit != q2.has_unit {
-        panic!("Unimplemented! Need to enable unit conversions in QuantityValue math");
+impl std::fmt::Display for UnitMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected unit {:?}, found {:?} (unit conversions are not yet supported in QuantityValue math)", self.expected, self.found)
     }
-    QuantityValue {
+}
+
+/// Determines whether two quantities can be combined without unit conversion
+pub fn units_match(q1: &QuantityValue, q2: &QuantityValue) -> bool {
+    q1.has_unit == q2.has_unit
+}
+
+pub fn add(q1: QuantityValue, q2: QuantityValue) -> Result<QuantityValue, UnitMismatch> {
+    if !units_match(&q1, &q2) {
+        return Err(UnitMismatch { expected: q1.has_unit, found: q2.has_unit });
+    }
+    Ok(QuantityValue {
         has_numerical_value: q1.has_numerical_value + q2.has_numerical_value,
         has_unit: q1.has_unit,
-    }
+    })
 }
 
-pub fn subtract(q1: QuantityValue, q2: QuantityValue) -> QuantityValue {
-    if q1.has_unit != q2.has_unit {
-        panic!("Unimplemented! Need to enable unit conversions in QuantityValue math");
+pub fn subtract(q1: QuantityValue, q2: QuantityValue) -> Result<QuantityValue, UnitMismatch> {
+    if !units_match(&q1, &q2) {
+        return Err(UnitMismatch { expected: q1.has_unit, found: q2.has_unit });
     }
-    QuantityValue {
+    Ok(QuantityValue {
         has_numerical_value: q1.has_numerical_value - q2.has_numerical_value,
         has_unit: q1.has_unit,
-    }
+    })
 }
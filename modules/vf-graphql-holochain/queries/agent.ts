@@ -1,13 +1,14 @@
 /**
  * Agent queries
  *
- * :TODO: wire into Personas hApp and replace generated agent names with serving of profile data
+ * Agents are served from their `Person` or `Organization` profile where one exists. Registered
+ * agents who have not yet created a profile are represented as a `Person` identified by their key.
  *
  * @package: Holo-REA
  * @since:   2020-02-19
  */
 
-import { DNAIdMappings, injectTypename } from '../types'
+import { DNAIdMappings } from '../types'
 import { mapZomeFn } from '../connection'
 
 import {
@@ -20,7 +21,32 @@ function serializeHash (hash) {
   return `u${Base64.fromUint8Array(hash, true)}`
 }
 
+// map zome agent profiles to the appropriate GraphQL interface implementation
+function toAgent (agent): Agent {
+  return {
+    ...agent,
+    __typename: agent.agentType,
+  }
+}
+
+// placeholder for registered agents who have not created a profile
+// :TODO: is a key-only agent always a 'Person' in Holochain, or will we allow users to act in an Organization context directly?
+function toRegisteredAgent (id: string, agentPubKey: string): Agent {
+  return {
+    id,
+    name: `Agent ${agentPubKey.substr(2, 4)}`,
+    __typename: 'Person',
+  }
+}
+
+function keyString (agentPubKey): string {
+  return typeof agentPubKey === 'string' ? agentPubKey : serializeHash(agentPubKey)
+}
+
 export default (dnaConfig: DNAIdMappings, conductorUri: string) => {
+  const readMyProfile = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent', 'whoami')
+  const readAllProfiles = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent', 'get_all_agents')
+  const readProfile = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent', 'get_agent')
   const readMyAgent = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent_registration', 'get_my_agent_pubkey')
   const readAllAgents = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent_registration', 'get_registered_agents')
   const agentExists = mapZomeFn(dnaConfig, conductorUri, 'agent', 'agent_registration', 'is_registered')
@@ -29,35 +55,38 @@ export default (dnaConfig: DNAIdMappings, conductorUri: string) => {
   const mappedDNA = dnaConfig['agent'] ? serializeHash(dnaConfig['agent'][0]) : null
 
   return {
-    // :TODO: is myAgent always a 'Person' in Holochain, or will we allow users to act in an Organization context directly?
-    myAgent: injectTypename('Person', async (root, args): Promise<Agent> => {
-      const agentPubKey = serializeHash(await readMyAgent(null))
-      // :TODO: wire to Personas hApp
-      return {
-        id: `${agentPubKey}:${mappedDNA}`,
-        name: `Agent ${agentPubKey.substr(2, 4)}`,
+    myAgent: async (root, args): Promise<Agent> => {
+      const res = await readMyProfile(null)
+      if (res) {
+        return toAgent(res.agent)
       }
-    }),
+      const agentPubKey = serializeHash(await readMyAgent(null))
+      return toRegisteredAgent(`${agentPubKey}:${mappedDNA}`, agentPubKey)
+    },
 
     agents: async (root, args): Promise<Agent[]> => {
-      return (await readAllAgents(null)).map(agentAddress => ({
-        // :TODO: wire to Personas hApp
-        id: agentAddress,
-        name: `Agent ${agentAddress.substr(2, 4)}`,
-        __typename: 'Person',  // :SHONK:
-      }))
+      const profiles = (await readAllProfiles(null)).map(res => res.agent)
+      const profileKeys = new Set(profiles.filter(a => a.agentPubKey).map(a => keyString(a.agentPubKey)))
+
+      return profiles.map(toAgent).concat(
+        (await readAllAgents(null))
+          .filter(agentAddress => !profileKeys.has(keyString(agentAddress)))
+          .map(agentAddress => toRegisteredAgent(agentAddress, agentAddress)),
+      )
     },
 
-    agent: injectTypename('Person', async (root, { id }): Promise<Agent> => {
-      const isAgent = await agentExists({ pubKey: id })
+    agent: async (root, { id }): Promise<Agent> => {
+      // profile IDs, and agent keys of agents who have created a profile
+      try {
+        return toAgent((await readProfile({ address: id })).agent)
+      } catch (e) {
+        const isAgent = await agentExists({ pubKey: id })
 
-      if (!isAgent) {
-        throw new Error('No agent exists with that ID')
+        if (!isAgent) {
+          throw new Error('No agent exists with that ID')
+        }
+        return toRegisteredAgent(id, id)
       }
-      return {
-        id,
-        name: `Agent ${id.substr(2, 4)}`,
-      }
-    }),
+    },
   }
 }
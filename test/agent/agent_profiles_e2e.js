@@ -0,0 +1,109 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+  mockAddress,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+runner.registerScenario('Person profiles are bound to agent keys', async (s, t) => {
+  const { cells: [alice] } = await buildPlayer(s, config, ['agent'])
+  const { cells: [bob] } = await buildPlayer(s, config, ['agent'])
+
+  let resp = await alice.call('agent', 'whoami', null)
+  t.equal(resp, null, 'no profile returned before one is created')
+
+  const person = {
+    agentType: 'Person',
+    name: 'Alice',
+    image: 'https://example.com/alice.png',
+    classifiedAs: ['https://example.com/gardener'],
+    note: 'grows vegetables',
+  }
+  const createResp = await alice.call('agent', 'create_agent', { agent: person })
+  t.ok(createResp.agent && createResp.agent.id, 'person created successfully')
+  t.deepEqual(createResp.agent.agentPubKey, alice.cellId[1], 'person bound to creating agent key')
+  await s.consistency()
+  const personId = createResp.agent.id
+
+  resp = await alice.call('agent', 'whoami', null)
+  t.deepEqual(resp.agent.id, personId, 'whoami returns own profile')
+  t.equal(resp.agent.name, person.name, 'Agent.name saved')
+  t.equal(resp.agent.image, person.image, 'Agent.image saved')
+  t.deepEqual(resp.agent.classifiedAs, person.classifiedAs, 'Agent.classifiedAs saved')
+
+  resp = await bob.call('agent', 'whois', { agentPubKey: alice.cellId[1] })
+  t.deepEqual(resp.agent.id, personId, 'other agents can look up a profile by key')
+
+  resp = await bob.call('agent', 'get_agent', { address: [alice.cellId[0], alice.cellId[1]] })
+  t.deepEqual(resp.agent.id, personId, 'agent key identifiers resolve to the agent\'s profile')
+
+  try {
+    await alice.call('agent', 'get_agent', { address: [bob.cellId[0], bob.cellId[1]] })
+    t.fail('profile returned for an agent key without one')
+  } catch (err) {
+    t.ok(err, 'agent key identifiers without a profile are not found')
+  }
+
+  resp = await bob.call('agent', 'whoami', null)
+  t.equal(resp, null, 'profiles are not shared between agents')
+
+  try {
+    await alice.call('agent', 'create_agent', { agent: { agentType: 'Person', name: 'Alice again' } })
+    t.fail('second person created for the same agent')
+  } catch (err) {
+    t.ok(err.data.data.includes('agent already has a Person profile'), 'duplicate person rejected')
+  }
+
+  // SCENARIO: update and remove the profile
+  resp = await alice.call('agent', 'update_agent', { agent: { revisionId: createResp.agent.revisionId, name: 'Alice G.' } })
+  t.equal(resp.agent.name, 'Alice G.', 'Agent.name updated')
+  t.equal(resp.agent.agentType, 'Person', 'Agent.agentType retained on update')
+  await s.consistency()
+
+  await alice.call('agent', 'delete_agent', { address: resp.agent.revisionId })
+  await s.consistency()
+
+  resp = await alice.call('agent', 'whoami', null)
+  t.equal(resp, null, 'profile unbound from agent key when deleted')
+})
+
+runner.registerScenario('Organizations act as economic agents', async (s, t) => {
+  const { cells: [agent, observation] } = await buildPlayer(s, config, ['agent', 'observation'])
+
+  const orgResp = await agent.call('agent', 'create_agent', { agent: {
+    agentType: 'Organization',
+    name: 'Community garden',
+    primaryLocation: mockAddress(false),
+  } })
+  t.ok(orgResp.agent && orgResp.agent.id, 'organization created successfully')
+  t.equal(orgResp.agent.agentPubKey, undefined, 'organization not bound to any agent key')
+  await s.consistency()
+  const orgId = orgResp.agent.id
+
+  const personResp = await agent.call('agent', 'create_agent', { agent: { agentType: 'Person', name: 'Member' } })
+  await s.consistency()
+  const personId = personResp.agent.id
+
+  let resp = await agent.call('agent', 'get_all_agents', null)
+  t.equal(resp.length, 2, 'persons and organizations listed together')
+
+  resp = await agent.call('agent', 'whoami', null)
+  t.deepEqual(resp.agent.id, personId, 'creating an organization does not bind the agent key')
+
+  const eventResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'work',
+    provider: personId,
+    receiver: orgId,
+    effortQuantity: { hasNumericalValue: 2, hasUnit: mockIdentifier(false) },
+    hasPointInTime: '2019-11-19T04:29:55.056Z',
+  } })
+  t.deepEqual(eventResp.economicEvent.provider, personId, 'person acts as event provider')
+  t.deepEqual(eventResp.economicEvent.receiver, orgId, 'organization acts as event receiver')
+})
+
+runner.run()
@@ -0,0 +1,109 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+  mockAgentId,
+  mockAddress,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+const testEventProps = {
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  provider: mockAgentId(false),
+  receiver: mockAgentId(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+runner.registerScenario('appreciations link gift events to the events they appreciate', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, config, ['observation'])
+
+  // SCENARIO: write records
+  const giftResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    note: 'a gift of produce',
+    action: 'raise',
+    ...testEventProps,
+  } })
+  await s.consistency()
+  const giftId = giftResp.economicEvent.id
+
+  const thanksResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    note: 'a return gift in appreciation',
+    action: 'raise',
+    ...testEventProps,
+  } })
+  await s.consistency()
+  const thanksId = thanksResp.economicEvent.id
+
+  const appreciation = {
+    appreciationOf: giftId,
+    appreciationWith: thanksId,
+    note: 'thanks for the vegetables',
+  }
+  const appreciationResp = await observation.call('appreciation', 'create_appreciation', { appreciation })
+  t.ok(appreciationResp.appreciation && appreciationResp.appreciation.id, 'appreciation created successfully')
+  await s.consistency()
+  const appreciationId = appreciationResp.appreciation.id
+
+  // ASSERT: check appreciation record
+  let readResponse = await observation.call('appreciation', 'get_appreciation', { address: appreciationId })
+  t.deepEqual(readResponse.appreciation.appreciationOf, giftId, 'Appreciation.appreciationOf reference saved')
+  t.deepEqual(readResponse.appreciation.appreciationWith, thanksId, 'Appreciation.appreciationWith reference saved')
+  t.equal(readResponse.appreciation.note, appreciation.note, 'Appreciation.note saved')
+
+  // ASSERT: check reciprocal links
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: giftId })
+  t.deepEqual(readResponse.economicEvent.appreciatedBy, [appreciationId], 'EconomicEvent.appreciatedBy reciprocal reference saved')
+
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: thanksId })
+  t.deepEqual(readResponse.economicEvent.appreciationOf, [appreciationId], 'EconomicEvent.appreciationOf reciprocal reference saved')
+
+  // ASSERT: check query indexes
+  readResponse = await observation.call('appreciation_index', 'query_appreciations', { params: { appreciationOf: giftId } })
+  t.equal(readResponse.results.length, 1, 'read appreciations by appreciated event OK')
+  t.deepEqual(readResponse.results[0].appreciation.id, appreciationId, 'Appreciation.appreciationOf indexed correctly')
+
+  readResponse = await observation.call('appreciation_index', 'query_appreciations', { params: { appreciationWith: thanksId } })
+  t.equal(readResponse.results.length, 1, 'read appreciations by gift event OK')
+  t.deepEqual(readResponse.results[0].appreciation.id, appreciationId, 'Appreciation.appreciationWith indexed correctly')
+
+  readResponse = await observation.call('economic_event_index', 'query_economic_events', { params: { appreciatedBy: appreciationId } })
+  t.equal(readResponse.results.length, 1, 'read events by appreciation OK')
+  t.deepEqual(readResponse.results[0].economicEvent.id, giftId, 'EconomicEvent.appreciatedBy indexed correctly')
+
+  // SCENARIO: remove the appreciation
+  await observation.call('appreciation', 'delete_appreciation', { address: appreciationResp.appreciation.revisionId })
+  await s.consistency()
+
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: giftId })
+  t.equal(readResponse.economicEvent.appreciatedBy, undefined, 'EconomicEvent.appreciatedBy reference removed')
+
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: thanksId })
+  t.equal(readResponse.economicEvent.appreciationOf, undefined, 'EconomicEvent.appreciationOf reference removed')
+})
+
+runner.registerScenario('appreciations must reference existing events', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, config, ['observation'])
+
+  const giftResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'raise',
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  try {
+    await observation.call('appreciation', 'create_appreciation', { appreciation: {
+      appreciationOf: giftResp.economicEvent.id,
+      appreciationWith: mockAddress(false),
+    } })
+    t.fail('appreciation created for a nonexistent event')
+  } catch (err) {
+    t.ok(err.data.data.includes('Appreciation.appreciationWith must reference an existing EconomicEvent'), 'missing event rejected')
+  }
+})
+
+runner.run()
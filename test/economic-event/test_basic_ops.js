@@ -30,4 +30,82 @@ runner.registerScenario('create simplest event', async (s, t) => {
   t.deepEqual(createEventResponse.economicEvent.inScopeOf, ['some-accounting-scope'], 'event inScopeOf saved')
 })
 
+runner.registerScenario('event trigger chains', async (s, t) => {
+  const { cells: [alice] } = await buildPlayer(s, config, ['observation'])
+
+  const eventProps = {
+    provider: mockAgentId(false),
+    receiver: mockAgentId(false),
+    hasPointInTime: '2019-11-19T12:12:42.739+01:00',
+    resourceClassifiedAs: ['some-resource-type'],
+    resourceQuantity: { hasNumericalValue: 1 },
+  }
+
+  const delivery = await alice.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'delivery', ...eventProps } })
+  await s.consistency()
+  const deliveryId = delivery.economicEvent.id
+  const payment = await alice.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'payment', triggeredBy: deliveryId, ...eventProps } })
+  await s.consistency()
+  const paymentId = payment.economicEvent.id
+  const receipt = await alice.call('economic_event', 'create_economic_event', { event: { action: 'transfer', note: 'receipt', triggeredBy: paymentId, ...eventProps } })
+  await s.consistency()
+  const receiptId = receipt.economicEvent.id
+
+  let readResp = await alice.call('economic_event', 'get_economic_event', { address: deliveryId })
+  t.deepEqual(readResp.economicEvent.triggers, [paymentId], 'triggered events listed on triggering event')
+
+  readResp = await alice.call('economic_event_index', 'query_economic_events', { params: { triggeredBy: deliveryId } })
+  t.equal(readResp.results.length, 1, 'events queryable by triggering event')
+
+  let chainResp = await alice.call('economic_event', 'get_trigger_chain', { address: deliveryId })
+  t.deepEqual(chainResp.edges.map(e => e.node.id), [paymentId, receiptId], 'full trigger chain walked in order')
+  t.deepEqual(chainResp.edges.map(e => e.depth), [1, 2], 'trigger chain depths reported')
+  t.equal(chainResp.truncated, false, 'complete chain not truncated')
+
+  chainResp = await alice.call('economic_event', 'get_trigger_chain', { address: deliveryId, maxDepth: 1 })
+  t.deepEqual(chainResp.edges.map(e => e.node.id), [paymentId], 'trigger chain limited to requested depth')
+  t.equal(chainResp.truncated, true, 'chain beyond depth limit flagged as truncated')
+})
+
+runner.registerScenario('event date validation', async (s, t) => {
+  const { cells: [alice] } = await buildPlayer(s, config, ['observation'])
+
+  const eventProps = {
+    action: 'raise',
+    provider: mockAgentId(false),
+    receiver: mockAgentId(false),
+    resourceClassifiedAs: ['some-resource-type'],
+    resourceQuantity: { hasNumericalValue: 1 },
+  }
+
+  try {
+    await alice.call('economic_event', 'create_economic_event', { event: {
+      hasBeginning: '2019-11-20T12:00:00.000+01:00',
+      hasEnd: '2019-11-19T12:00:00.000+01:00',
+      ...eventProps,
+    } })
+    t.fail('event ending before it began was created')
+  } catch (err) {
+    t.ok(err.data.data.includes('hasBeginning must not be later than hasEnd'), 'event beginning after end rejected')
+  }
+
+  try {
+    await alice.call('economic_event', 'create_economic_event', { event: {
+      hasPointInTime: '2019-11-19T12:00:00.000+01:00',
+      hasBeginning: '2019-11-19T11:00:00.000+01:00',
+      ...eventProps,
+    } })
+    t.fail('event with both exact time and beginning was created')
+  } catch (err) {
+    t.ok(err.data.data.includes('exact time or a beginning'), 'event with exact time and beginning rejected')
+  }
+
+  const resp = await alice.call('economic_event', 'create_economic_event', { event: {
+    hasBeginning: '2019-11-19T12:00:00.000+01:00',
+    hasEnd: '2019-11-19T12:30:00.000+00:00',
+    ...eventProps,
+  } })
+  t.ok(resp.economicEvent, 'dates are compared across offsets')
+})
+
 runner.run()
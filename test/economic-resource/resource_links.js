@@ -132,6 +132,41 @@ runner.registerScenario('EconomicResource composition / containment functionalit
   t.equal(readResp.data.container.contains[0].id, resourceId2, 'contains ref OK in GraphQL API')
   t.equal(readResp.data.contained.containedIn.id, resourceId1, 'containedIn ref OK in GraphQL API')
 
+  // SCENARIO: combine & separate events alter containment
+  const pResp = await alice.call('process', 'create_process', { process: { name: 'packing process' } })
+  await s.consistency()
+  t.ok(pResp.process && pResp.process.id, 'process created successfully')
+  const processId = pResp.process.id
+
+  const combineResp = await alice.call('economic_event', 'create_economic_event', { event: {
+    action: 'combine',
+    inputOf: processId,
+    resourceInventoriedAs: resourceId3,
+    toResourceInventoriedAs: resourceId1,
+    ...testEventProps,
+  } })
+  await s.consistency()
+  t.ok(combineResp.economicEvent, 'combine event created successfully')
+
+  readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId3 })
+  t.deepEqual(readResp.economicResource.containedIn, resourceId1, 'combine event sets containedIn')
+  readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId1 })
+  t.equal(readResp.economicResource.contains && readResp.economicResource.contains.length, 2, 'combine event appends container reference')
+
+  const separateResp = await alice.call('economic_event', 'create_economic_event', { event: {
+    action: 'separate',
+    outputOf: processId,
+    resourceInventoriedAs: resourceId3,
+    ...testEventProps,
+  } })
+  await s.consistency()
+  t.ok(separateResp.economicEvent, 'separate event created successfully')
+
+  readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId3 })
+  t.notOk(readResp.economicResource.containedIn, 'separate event clears containedIn')
+  readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId1 })
+  t.equal(readResp.economicResource.contains && readResp.economicResource.contains.length, 1, 'separate event removes container reference')
+
   // SCENARIO: delete resource, check links are removed
   // :TODO: needs some thought
   // const dResp = await alice.call('economic_resource', 'delete_resource', { address: resourceId3 })
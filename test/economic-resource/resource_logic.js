@@ -307,6 +307,180 @@ runner.registerScenario('EconomicResource & EconomicEvent record interactions',
     ['http://www.productontology.org/doc/Apple.ttl', 'http://www.productontology.org/doc/Manure_spreader.ttl'],
     'multiple events with the same ResourceClassification yield only 1 occurence of the classification in the resource data'
   )
+
+  // SCENARIO: rebuild resource quantities from the event log
+  let recalcResp = await observation.call('economic_resource', 'recalculate_economic_resource', { address: resourceId })
+  t.deepEqual(recalcResp.accountingQuantity, readResource.accountingQuantity, 'replayed accounting quantity matches stored value')
+  t.deepEqual(recalcResp.onhandQuantity, readResource.onhandQuantity, 'replayed onhand quantity matches stored value')
+  t.equal(recalcResp.discrepancies.length, 0, 'no discrepancies reported for consistent resource')
+  t.equal(recalcResp.corrected, false, 'no corrective revision written for consistent resource')
+
+  recalcResp = await observation.call('economic_resource', 'recalculate_economic_resource', { address: resourceId2 })
+  t.equal(recalcResp.discrepancies.length, 0, 'events received via transfers are replayed on the receiving side')
+
+  // SCENARIO: deleting an event reverses its inventory effects
+  newEvent = {
+    resourceInventoriedAs: resourceId,
+    action: 'raise',
+    resourceQuantity: { hasNumericalValue: 5, hasUnit: resourceUnitId },
+    ...testEventProps,
+  }
+  eventResp = await observation.call('economic_event', 'create_economic_event', { event: newEvent })
+  await s.consistency()
+  t.ok(eventResp.economicEvent, 'appending event to be deleted OK')
+
+  const delResp = await observation.call('economic_event', 'delete_economic_event', { address: eventResp.economicEvent.revisionId })
+  await s.consistency()
+  t.ok(delResp, 'event deleted OK')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.deepEqual(readResp.economicResource.accountingQuantity, readResource.accountingQuantity, 'deleted event no longer affects accountingQuantity')
+  t.deepEqual(readResp.economicResource.onhandQuantity, readResource.onhandQuantity, 'deleted event no longer affects onhandQuantity')
+
+  readResp = await observation.call('economic_resource_index', 'query_economic_resources', { params: { affectedBy: eventResp.economicEvent.id } })
+  t.equal(readResp.results.length, 0, 'affected_by index removed for deleted event')
+
+  // SCENARIO: correcting an event nets out its inventory effects
+  newEvent = {
+    resourceInventoriedAs: resourceId,
+    action: 'raise',
+    resourceQuantity: { hasNumericalValue: 10, hasUnit: resourceUnitId },
+    ...testEventProps,
+  }
+  eventResp = await observation.call('economic_event', 'create_economic_event', { event: newEvent })
+  await s.consistency()
+  t.ok(eventResp.economicEvent, 'appending event to be corrected OK')
+  const correctedEventId = eventResp.economicEvent.id
+  const correctedEventRevisionId = eventResp.economicEvent.revisionId
+
+  newEvent = {
+    resourceInventoriedAs: resourceId,
+    action: 'raise',
+    resourceQuantity: { hasNumericalValue: 3, hasUnit: resourceUnitId },
+    corrects: correctedEventId,
+    ...testEventProps,
+  }
+  eventResp = await observation.call('economic_event', 'create_economic_event', { event: newEvent })
+  await s.consistency()
+  t.ok(eventResp.economicEvent, 'correction event created OK')
+  t.deepEqual(eventResp.economicEvent.corrects, correctedEventId, 'correction event references corrected event')
+  const correctionEventId = eventResp.economicEvent.id
+  const correctionEventRevisionId = eventResp.economicEvent.revisionId
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(
+    readResp.economicResource.accountingQuantity.hasNumericalValue,
+    readResource.accountingQuantity.hasNumericalValue + 3,
+    'corrected event is netted out of accountingQuantity, correction is applied',
+  )
+
+  readResp = await observation.call('economic_event', 'get_economic_event', { address: correctedEventId })
+  t.deepEqual(readResp.economicEvent.correctedBy, correctionEventId, 'corrected event remains readable & references its correction')
+
+  readResp = await observation.call('economic_event_index', 'query_economic_events', { params: { corrects: correctedEventId } })
+  t.equal(readResp.results.length, 1, 'correction queryable by corrected event')
+
+  recalcResp = await observation.call('economic_resource', 'recalculate_economic_resource', { address: resourceId })
+  t.equal(recalcResp.discrepancies.length, 0, 'replayed event log nets out corrected event')
+
+  // SCENARIO: deleting a corrected event removes its correction links
+  await observation.call('economic_event', 'delete_economic_event', { address: correctedEventRevisionId })
+  await s.consistency()
+
+  readResp = await observation.call('economic_event_index', 'query_economic_events', { params: { corrects: correctedEventId } })
+  t.equal(readResp.results.length, 0, 'correction no longer queryable by deleted event')
+
+  // SCENARIO: deleting an event is refused if reversing it would leave a negative balance
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  newEvent = {
+    resourceInventoriedAs: resourceId,
+    action: 'lower',
+    resourceQuantity: { hasNumericalValue: readResp.economicResource.accountingQuantity.hasNumericalValue, hasUnit: resourceUnitId },
+    ...testEventProps,
+  }
+  eventResp = await observation.call('economic_event', 'create_economic_event', { event: newEvent })
+  await s.consistency()
+  t.ok(eventResp.economicEvent, 'inventory drawn down to zero')
+
+  try {
+    await observation.call('economic_event', 'delete_economic_event', { address: correctionEventRevisionId })
+    t.fail('deleting event which would leave a negative balance should be rejected')
+  } catch (e) {
+    t.ok(e.toString().match(/negative balance/i), 'deleting event which would leave a negative balance is rejected')
+  }
+
+  readResp = await observation.call('economic_event', 'get_economic_event', { address: correctionEventId })
+  t.ok(readResp.economicEvent, 'rejected event deletion leaves event in place')
+})
+
+runner.registerScenario('deleting the only event affecting an EconomicResource zeroes its quantities', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, config, ['observation'])
+  const resourceUnitId = mockIdentifier(false)
+
+  const cResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 8, hasUnit: resourceUnitId },
+      resourceClassifiedAs: ['some-classification-url'],
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'mistakenly recorded inventory' },
+  })
+  await s.consistency()
+  const resourceId = cResp.economicResource.id
+
+  await observation.call('economic_event', 'delete_economic_event', { address: cResp.economicEvent.revisionId })
+  await s.consistency()
+
+  const readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.deepEqual(readResp.economicResource.accountingQuantity, { hasNumericalValue: 0, hasUnit: resourceUnitId }, 'accountingQuantity zeroed in original unit')
+  t.deepEqual(readResp.economicResource.onhandQuantity, { hasNumericalValue: 0, hasUnit: resourceUnitId }, 'onhandQuantity zeroed in original unit')
+})
+
+runner.registerScenario('EconomicResource balance reflects events authored concurrently by different agents', async (s, t) => {
+  const { cells: [alice] } = await buildPlayer(s, config, ['observation'])
+  const { cells: [bob] } = await buildPlayer(s, config, ['observation'])
+  const resourceUnitId = mockIdentifier(false)
+
+  const cResp = await alice.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 10, hasUnit: resourceUnitId },
+      resourceClassifiedAs: ['some-classification-url'],
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'shared inventory' },
+  })
+  await s.consistency()
+  const resourceId = cResp.economicResource.id
+
+  // both agents apply their events to the same resource revision
+  const lowerEvent = (qty) => ({ event: {
+    action: 'lower',
+    resourceInventoriedAs: resourceId,
+    resourceQuantity: { hasNumericalValue: qty, hasUnit: resourceUnitId },
+    ...testEventProps,
+  } })
+  const [aliceResp, bobResp] = await Promise.all([
+    alice.call('economic_event', 'create_economic_event', lowerEvent(2)),
+    bob.call('economic_event', 'create_economic_event', lowerEvent(3)),
+  ])
+  t.ok(aliceResp.economicEvent && bobResp.economicEvent, 'concurrent events created')
+  await s.consistency()
+
+  let readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 5, 'both concurrent events reflected in accountingQuantity')
+  t.equal(readResp.economicResource.onhandQuantity.hasNumericalValue, 5, 'both concurrent events reflected in onhandQuantity')
+
+  readResp = await bob.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 5, 'other agent reads the same balance')
+
+  // subsequent events build upon the combined balance
+  await bob.call('economic_event', 'create_economic_event', lowerEvent(1))
+  await s.consistency()
+
+  readResp = await alice.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 4, 'later events apply on top of the combined balance')
 })
 
 runner.run()
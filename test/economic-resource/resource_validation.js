@@ -127,4 +127,226 @@ runner.registerScenario('EconomicResource classification fields validation', asy
   t.ok(resp.data.createEconomicEvent.economicEvent.id, 'creating resource with resource specification is OK')
 })
 
+runner.registerScenario('EconomicResource negative balance policy', async (s, t) => {
+  const alice = await buildPlayer(s, config, ['observation', 'specification'])
+  const { cells: [observation, specification] } = alice
+
+  const rsResp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: {
+    name: 'physical goods which cannot go below zero',
+    allowNegative: false,
+  } })
+  await s.consistency()
+  t.equal(rsResp.resourceSpecification.allowNegative, false, 'negative balance policy stored')
+  const resourceSpecificationId = rsResp.resourceSpecification.id
+
+  const cResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 2, hasUnit: kilograms },
+      ...testEventProps,
+    },
+    new_inventoried_resource: { conformsTo: resourceSpecificationId },
+  })
+  await s.consistency()
+  const resourceId = cResp.economicResource.id
+
+  const mutation = `
+    mutation($e: EconomicEventCreateParams!) {
+      createEconomicEvent(event: $e) {
+        economicEvent {
+          id
+        }
+      }
+    }
+  `
+
+  let resp = await alice.graphQL(mutation, {
+    e: {
+      action: 'lower',
+      resourceInventoriedAs: resourceId,
+      resourceQuantity: { hasNumericalValue: 3, hasUnit: kilograms },
+      ...testEventProps,
+    },
+  })
+  await s.consistency()
+
+  t.equal(resp.errors && resp.errors.length, 1, 'event producing a negative balance is rejected')
+  t.notEqual(-1, resp.errors[0].message.indexOf('accounting quantity short by 1'), 'shortfall reported in error')
+
+  resp = await alice.graphQL(mutation, {
+    e: {
+      action: 'lower',
+      resourceInventoriedAs: resourceId,
+      resourceQuantity: { hasNumericalValue: 2, hasUnit: kilograms },
+      ...testEventProps,
+    },
+  })
+  await s.consistency()
+
+  t.ok(resp.data.createEconomicEvent.economicEvent.id, 'event reducing balance to zero is OK')
+})
+
+runner.registerScenario('EconomicEvent batch creation is all-or-nothing', async (s, t) => {
+  const { cells: [observation, specification] } = await buildPlayer(s, config, ['observation', 'specification'])
+
+  const rsResp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: {
+    name: 'goods which cannot go below zero',
+    allowNegative: false,
+  } })
+  await s.consistency()
+  const resourceSpecificationId = rsResp.resourceSpecification.id
+
+  const cResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 5, hasUnit: kilograms },
+      ...testEventProps,
+    },
+    new_inventoried_resource: { conformsTo: resourceSpecificationId },
+  })
+  await s.consistency()
+  const resourceId = cResp.economicResource.id
+
+  let listResp = await observation.call('economic_event', 'get_all_economic_events', null)
+  const eventCount = listResp.edges.length
+
+  // each event is valid alone, but together they overdraw the resource
+  try {
+    await observation.call('economic_event', 'create_economic_events', { events: [
+      { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 3, hasUnit: kilograms }, ...testEventProps } },
+      { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 3, hasUnit: kilograms }, ...testEventProps } },
+    ] })
+    t.fail('overdrawing batch should be rejected')
+  } catch (err) {
+    t.ok(err.data.data.includes('event 1 in batch'), 'failing event in batch reported')
+  }
+  await s.consistency()
+
+  listResp = await observation.call('economic_event', 'get_all_economic_events', null)
+  t.equal(listResp.edges.length, eventCount, 'no events written for rejected batch')
+
+  let readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 5, 'resource unaffected by rejected batch')
+
+  // last event in the batch is rejected by up-front validation
+  try {
+    await observation.call('economic_event', 'create_economic_events', { events: [
+      { event: { action: 'raise', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms }, ...testEventProps } },
+      { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms }, ...testEventProps } },
+      { event: { action: 'not-a-real-action', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms }, ...testEventProps } },
+    ] })
+    t.fail('batch with invalid last event should be rejected')
+  } catch (err) {
+    t.ok(err.data.data.includes('event 2 in batch'), 'invalid last event in batch reported')
+  }
+  await s.consistency()
+
+  listResp = await observation.call('economic_event', 'get_all_economic_events', null)
+  t.equal(listResp.edges.length, eventCount, 'no events persisted when last event in batch is invalid')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 5, 'resource unaffected when last event in batch is invalid')
+
+  // last event in the batch passes validation, but fails when written as it references a nonexistent Process
+  try {
+    await observation.call('economic_event', 'create_economic_events', { events: [
+      { event: { action: 'raise', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 2, hasUnit: kilograms }, ...testEventProps } },
+      { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms }, ...testEventProps } },
+      { event: { action: 'consume', inputOf: mockAddress(), resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms }, ...testEventProps } },
+    ] })
+    t.fail('batch with unwritable last event should be rejected')
+  } catch (err) {
+    t.ok(err.data.data.includes('event 2 in batch'), 'failing last event in batch reported')
+    t.notOk(err.data.data.includes('could not be rolled back'), 'all events written before the failure rolled back')
+  }
+  await s.consistency()
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 5, 'inventory effects of rolled back events reversed')
+
+  const batchResp = await observation.call('economic_event', 'create_economic_events', { events: [
+    { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 3, hasUnit: kilograms }, ...testEventProps } },
+    { event: { action: 'lower', resourceInventoriedAs: resourceId, resourceQuantity: { hasNumericalValue: 2, hasUnit: kilograms }, ...testEventProps } },
+  ] })
+  await s.consistency()
+  t.equal(batchResp.length, 2, 'valid batch creates all events')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 0, 'all batch effects applied to resource')
+})
+
+runner.registerScenario('EconomicEvent idempotency keys prevent duplicate inventory effects', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, config, ['observation'])
+
+  const cResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 5, hasUnit: kilograms },
+      resourceClassifiedAs: ['some-classification-url'],
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'resource for idempotency checks' },
+  })
+  await s.consistency()
+  const resourceId = cResp.economicResource.id
+
+  const lowerEvent = (idempotencyKey) => ({
+    action: 'lower',
+    resourceInventoriedAs: resourceId,
+    resourceQuantity: { hasNumericalValue: 1, hasUnit: kilograms },
+    idempotencyKey,
+    ...testEventProps,
+  })
+
+  const first = await observation.call('economic_event', 'create_economic_event', { event: lowerEvent('request-1') })
+  await s.consistency()
+  const retried = await observation.call('economic_event', 'create_economic_event', { event: lowerEvent('request-1') })
+  await s.consistency()
+  t.equal(retried.economicEvent.id, first.economicEvent.id, 'retried request returns existing event')
+
+  let readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 4, 'retried request does not re-apply inventory effects')
+
+  const second = await observation.call('economic_event', 'create_economic_event', { event: lowerEvent('request-2') })
+  await s.consistency()
+  t.notEqual(second.economicEvent.id, first.economicEvent.id, 'identical event with a different key is a distinct record')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 3, 'distinct event applies its inventory effects')
+
+  // events submitted without a key are never treated as duplicates
+  const keyless1 = await observation.call('economic_event', 'create_economic_event', { event: lowerEvent(undefined) })
+  await s.consistency()
+  const keyless2 = await observation.call('economic_event', 'create_economic_event', { event: lowerEvent(undefined) })
+  await s.consistency()
+  t.notEqual(keyless2.economicEvent.revisionId, keyless1.economicEvent.revisionId, 'identical keyless events are both stored')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: resourceId })
+  t.equal(readResp.economicResource.accountingQuantity.hasNumericalValue, 1, 'identical keyless events both apply their inventory effects')
+
+  // retried requests which create a resource must not create it again
+  const createResourceRequest = {
+    event: {
+      action: 'raise',
+      resourceQuantity: { hasNumericalValue: 2, hasUnit: kilograms },
+      resourceClassifiedAs: ['some-classification-url'],
+      idempotencyKey: 'request-3',
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'resource created by a retried request' },
+  }
+  const created = await observation.call('economic_event', 'create_economic_event', createResourceRequest)
+  await s.consistency()
+  let listResp = await observation.call('economic_resource', 'get_all_economic_resources', null)
+  const resourceCount = listResp.edges.length
+
+  const recreated = await observation.call('economic_event', 'create_economic_event', createResourceRequest)
+  await s.consistency()
+  t.equal(recreated.economicEvent.id, created.economicEvent.id, 'retried request creating a resource returns existing event')
+  t.equal(recreated.economicResource.id, created.economicResource.id, 'retried request creating a resource returns existing resource')
+
+  listResp = await observation.call('economic_resource', 'get_all_economic_resources', null)
+  t.equal(listResp.edges.length, resourceCount, 'retried request does not create another resource')
+})
+
 runner.run()
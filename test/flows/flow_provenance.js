@@ -0,0 +1,126 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockAgentId,
+  mockIdentifier,
+} = require('../init')
+
+const runner = buildRunner()
+
+const unitId = mockIdentifier(false)
+
+const testEventProps = {
+  provider: mockAgentId(false),
+  receiver: mockAgentId(false),
+  hasPointInTime: '2019-11-19T12:12:42.739+01:00',
+}
+
+runner.registerScenario('trace & track provenance through resources, events & processes', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, buildConfig(), ['observation'])
+
+  // SCENARIO: raw material -> process -> finished product -> transferred to customer
+  const rawResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceClassifiedAs: ['raw-material'],
+      resourceQuantity: { hasNumericalValue: 10, hasUnit: unitId },
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'raw material' },
+  })
+  await s.consistency()
+  const raiseEventId = rawResp.economicEvent.id
+  const rawResourceId = rawResp.economicResource.id
+
+  const pResp = await observation.call('process', 'create_process', { process: { name: 'manufacturing' } })
+  await s.consistency()
+  const processId = pResp.process.id
+
+  const consumeResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'consume',
+      inputOf: processId,
+      resourceInventoriedAs: rawResourceId,
+      resourceQuantity: { hasNumericalValue: 2, hasUnit: unitId },
+      ...testEventProps,
+    },
+  })
+  await s.consistency()
+  const consumeEventId = consumeResp.economicEvent.id
+
+  const produceResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'produce',
+      outputOf: processId,
+      resourceClassifiedAs: ['finished-product'],
+      resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'finished product' },
+  })
+  await s.consistency()
+  const produceEventId = produceResp.economicEvent.id
+  const productResourceId = produceResp.economicResource.id
+
+  const customerResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'raise',
+      resourceClassifiedAs: ['finished-product'],
+      resourceQuantity: { hasNumericalValue: 0, hasUnit: unitId },
+      ...testEventProps,
+    },
+    new_inventoried_resource: { name: 'customer inventory' },
+  })
+  await s.consistency()
+  const customerResourceId = customerResp.economicResource.id
+
+  const transferResp = await observation.call('economic_event', 'create_economic_event', {
+    event: {
+      action: 'transfer',
+      resourceInventoriedAs: productResourceId,
+      toResourceInventoriedAs: customerResourceId,
+      resourceQuantity: { hasNumericalValue: 1, hasUnit: unitId },
+      ...testEventProps,
+    },
+  })
+  await s.consistency()
+  const transferEventId = transferResp.economicEvent.id
+
+  // SCENARIO: one-step trace & track fields
+  let readResp = await observation.call('process', 'get_process', { address: processId })
+  t.deepEqual(readResp.process.trace, [consumeEventId], 'process trace lists input events')
+  t.deepEqual(readResp.process.track, [produceEventId], 'process track lists output events')
+
+  readResp = await observation.call('economic_resource', 'get_economic_resource', { address: rawResourceId })
+  t.deepEqual(readResp.economicResource.trace, [raiseEventId], 'resource trace lists incoming events')
+  t.deepEqual(readResp.economicResource.track, [consumeEventId], 'resource track lists outgoing events')
+
+  // SCENARIO: full traversal
+  let resp = await observation.call('economic_event', 'trace_provenance', { start: { EconomicEvent: transferEventId } })
+  t.deepEqual(resp.edges.map(e => e.node), [
+    { EconomicResource: productResourceId },
+    { EconomicEvent: produceEventId },
+    { Process: processId },
+    { EconomicEvent: consumeEventId },
+    { EconomicResource: rawResourceId },
+    { EconomicEvent: raiseEventId },
+  ], 'trace walks from finished product back to raw inputs')
+  t.equal(resp.truncated, false, 'complete trace not truncated')
+
+  resp = await observation.call('economic_event', 'track_provenance', { start: { EconomicResource: rawResourceId } })
+  t.deepEqual(resp.edges.map(e => e.node), [
+    { EconomicEvent: consumeEventId },
+    { Process: processId },
+    { EconomicEvent: produceEventId },
+    { EconomicResource: productResourceId },
+    { EconomicEvent: transferEventId },
+    { EconomicResource: customerResourceId },
+  ], 'track walks from raw inputs forward to their eventual uses')
+
+  resp = await observation.call('economic_event', 'trace_provenance', { start: { EconomicEvent: transferEventId }, maxDepth: 2 })
+  t.equal(resp.edges.length, 2, 'trace limited to requested depth')
+  t.equal(resp.truncated, true, 'trace beyond depth limit flagged as truncated')
+})
+
+runner.run()
@@ -178,6 +178,14 @@ module.exports = {
     ]
     return asStr ? seralizeId(a) : a
   },
+  // agent profile (`Person` / `Organization`) records, as referenced by agent identifiers read back from indexes
+  mockProfileId: (asStr = true) => {
+    const a = [
+      Buffer.from(concatenate(HOLOHASH_PREFIX_DNA, randomBytes(HOLOCHAIN_RAW_IDENTIFIER_LEN).buffer)),
+      Buffer.from(concatenate(HOLOHASH_PREFIX_ENTRY, randomBytes(HOLOCHAIN_RAW_IDENTIFIER_LEN).buffer)),
+    ]
+    return asStr ? seralizeId(a) : a
+  },
   mockAddress: (asStr = true) => {
     const a = [
       Buffer.from(concatenate(HOLOHASH_PREFIX_DNA, randomBytes(HOLOCHAIN_RAW_IDENTIFIER_LEN).buffer)),
@@ -0,0 +1,212 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+  mockAgentId,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+const quantity = (hasNumericalValue) => ({ hasNumericalValue, hasUnit: mockIdentifier(false) })
+
+runner.registerScenario('plans can be generated from recipes', async (s, t) => {
+  const { cells: [observation, planning, specification] } = await buildPlayer(s, config, ['observation', 'planning', 'specification'])
+
+  // SCENARIO: write specifications & a two-step recipe for bread
+  let resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'bread' } })
+  const breadSpecId = resp.resourceSpecification.id
+  resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'flour' } })
+  const flourSpecId = resp.resourceSpecification.id
+  resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'wheat' } })
+  const wheatSpecId = resp.resourceSpecification.id
+  resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'oven' } })
+  const ovenSpecId = resp.resourceSpecification.id
+  resp = await specification.call('process_specification', 'create_process_specification', { process_specification: { name: 'bake' } })
+  const bakeSpecId = resp.processSpecification.id
+  await s.consistency()
+
+  resp = await specification.call('recipe_process', 'create_recipe_process', { recipe_process: {
+    name: 'bake bread',
+    processConformsTo: bakeSpecId,
+    hasDuration: { numericDuration: 2, unitType: 'hour' },
+  } })
+  const bakeRecipeId = resp.recipeProcess.id
+  resp = await specification.call('recipe_process', 'create_recipe_process', { recipe_process: {
+    name: 'mill flour',
+    hasDuration: { numericDuration: 1, unitType: 'day' },
+  } })
+  const millRecipeId = resp.recipeProcess.id
+  resp = await specification.call('recipe_process', 'create_recipe_process', { recipe_process: {
+    name: 'build oven',
+  } })
+  const ovenRecipeId = resp.recipeProcess.id
+  await s.consistency()
+
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'produce',
+    recipeOutputOf: bakeRecipeId,
+    resourceConformsTo: breadSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'consume',
+    recipeInputOf: bakeRecipeId,
+    resourceConformsTo: flourSpecId,
+    resourceQuantity: quantity(500),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'use',
+    recipeInputOf: bakeRecipeId,
+    resourceConformsTo: ovenSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'produce',
+    recipeOutputOf: ovenRecipeId,
+    resourceConformsTo: ovenSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'produce',
+    recipeOutputOf: millRecipeId,
+    resourceConformsTo: flourSpecId,
+    resourceQuantity: quantity(500),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'consume',
+    recipeInputOf: millRecipeId,
+    resourceConformsTo: wheatSpecId,
+    resourceQuantity: quantity(600),
+  } })
+  await s.consistency()
+
+  // SCENARIO: generate a plan for 3 loaves
+  const agentId = mockAgentId()
+  resp = await planning.call('plan', 'create_plan_from_recipe', { plan: {
+    name: 'bake 3 loaves',
+    resourceConformsTo: breadSpecId,
+    resourceQuantity: quantity(3),
+    due: '2019-12-01T00:00:00.056+10:00',
+    processDna: observation.cellId[0],
+    provider: agentId,
+    receiver: agentId,
+  } })
+  t.ok(resp.plan && resp.plan.id, 'plan generated successfully')
+  await s.consistency()
+  const planId = resp.plan.id
+
+  resp = await planning.call('plan', 'get_plan', { address: planId })
+  t.equal(resp.plan.name, 'bake 3 loaves', 'Plan.name saved')
+  t.equal(resp.plan.due, '2019-12-01T00:00:00.056+10:00', 'Plan.due saved')
+  t.equal(resp.plan.processes && resp.plan.processes.length, 3, 'a process is planned for each recipe')
+  t.equal(resp.plan.independentDemands && resp.plan.independentDemands.length, 1, 'requested output is the independent demand')
+
+  resp = await planning.call('commitment', 'get_commitment', { address: resp.plan.independentDemands[0] })
+  t.equal(resp.commitment.resourceConformsTo, breadSpecId, 'independent demand is for the requested resource')
+  t.equal(resp.commitment.resourceQuantity.hasNumericalValue, 3, 'independent demand is for the requested quantity')
+
+  // ASSERT: check planned processes are scheduled backwards from the plan due date
+  resp = await observation.call('process_index', 'query_processes', { params: { plannedWithin: planId } })
+  const processes = resp.results.map(r => r.process)
+  const bake = processes.find(p => p.name === 'bake bread')
+  const mill = processes.find(p => p.name === 'mill flour')
+  const oven = processes.find(p => p.name === 'build oven')
+  t.ok(bake && mill, 'processes created within plan')
+  t.ok(oven, 'process planned for resources used as well as consumed')
+  t.equal(bake.basedOn, bakeSpecId, 'Process.basedOn set from recipe')
+  t.equal(bake.hasEnd, '2019-12-01T00:00:00.056+10:00', 'final process ends when plan is due')
+  t.equal(bake.hasBeginning, '2019-11-30T22:00:00.056+10:00', 'final process begins its duration before')
+  t.equal(mill.hasEnd, '2019-11-30T22:00:00.056+10:00', 'dependant process ends when its output is needed')
+  t.equal(mill.hasBeginning, '2019-11-29T22:00:00.056+10:00', 'dependant process begins its duration before')
+
+  // ASSERT: check commitment quantities are scaled to the requested output
+  resp = await planning.call('commitment_index', 'query_commitments', { params: { inputOf: bake.id } })
+  t.equal(resp.results.length, 2, 'process input commitments created')
+  const flourInput = resp.results.map(r => r.commitment).find(c => c.resourceConformsTo === flourSpecId)
+  t.equal(flourInput.resourceQuantity.hasNumericalValue, 1500, 'input quantity scaled')
+  t.equal(flourInput.due, '2019-11-30T22:00:00.056+10:00', 'input due at process beginning')
+
+  resp = await planning.call('commitment_index', 'query_commitments', { params: { outputOf: oven.id } })
+  t.equal(resp.results.length, 1, 'used resource output commitment created')
+  t.equal(oven.hasEnd, '2019-11-30T22:00:00.056+10:00', 'used resource is due when the process using it begins')
+
+  resp = await planning.call('commitment_index', 'query_commitments', { params: { outputOf: mill.id } })
+  t.equal(resp.results.length, 1, 'dependant process output commitment created')
+  t.equal(resp.results[0].commitment.resourceQuantity.hasNumericalValue, 1500, 'dependant output quantity scaled to meet demand')
+  t.equal(resp.results[0].commitment.independentDemandOf, undefined, 'dependant output is not an independent demand')
+
+  resp = await planning.call('commitment_index', 'query_commitments', { params: { inputOf: mill.id } })
+  t.equal(resp.results[0].commitment.resourceQuantity.hasNumericalValue, 1800, 'dependant input quantity scaled')
+})
+
+runner.registerScenario('plans cannot be generated for resources without recipes', async (s, t) => {
+  const { cells: [observation, planning, specification] } = await buildPlayer(s, config, ['observation', 'planning', 'specification'])
+
+  const resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'mystery' } })
+  await s.consistency()
+
+  const agentId = mockAgentId()
+  try {
+    await planning.call('plan', 'create_plan_from_recipe', { plan: {
+      name: 'impossible plan',
+      resourceConformsTo: resp.resourceSpecification.id,
+      resourceQuantity: quantity(1),
+      due: '2019-12-01T00:00:00.056+10:00',
+      processDna: observation.cellId[0],
+      provider: agentId,
+      receiver: agentId,
+    } })
+    t.fail('plan generated without a recipe')
+  } catch (err) {
+    t.ok(err.data.data.includes('no recipe produces the requested ResourceSpecification'), 'missing recipe reported')
+  }
+})
+
+runner.registerScenario('plans cannot be generated from cyclic recipes', async (s, t) => {
+  const { cells: [observation, planning, specification] } = await buildPlayer(s, config, ['observation', 'planning', 'specification'])
+
+  let resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'sourdough starter' } })
+  const starterSpecId = resp.resourceSpecification.id
+  resp = await specification.call('recipe_process', 'create_recipe_process', { recipe_process: { name: 'feed starter' } })
+  const feedRecipeId = resp.recipeProcess.id
+  await s.consistency()
+
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'produce',
+    recipeOutputOf: feedRecipeId,
+    resourceConformsTo: starterSpecId,
+    resourceQuantity: quantity(2),
+  } })
+  await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'consume',
+    recipeInputOf: feedRecipeId,
+    resourceConformsTo: starterSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  await s.consistency()
+
+  const agentId = mockAgentId()
+  try {
+    await planning.call('plan', 'create_plan_from_recipe', { plan: {
+      name: 'endless starter',
+      resourceConformsTo: starterSpecId,
+      resourceQuantity: quantity(1),
+      due: '2019-12-01T00:00:00.056+10:00',
+      processDna: observation.cellId[0],
+      provider: agentId,
+      receiver: agentId,
+    } })
+    t.fail('plan generated from a cyclic recipe')
+  } catch (err) {
+    t.ok(err.data.data.includes('recipe contains a cycle'), 'cyclic recipe reported')
+  }
+  await s.consistency()
+
+  resp = await observation.call('process_index', 'query_processes', { params: { outputSpecifications: starterSpecId } })
+  t.equal(resp.results.length, 0, 'no processes left behind by the failed plan')
+})
+
+runner.run()
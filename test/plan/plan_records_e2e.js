@@ -0,0 +1,138 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+  mockAgentId,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+const testCommitmentProps = {
+  action: 'produce',
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  provider: mockAgentId(false),
+  receiver: mockAgentId(false),
+  due: '2019-11-19T04:29:55.056+10:00',
+}
+
+runner.registerScenario('plan records can be created, updated & deleted', async (s, t) => {
+  const { cells: [planning] } = await buildPlayer(s, config, ['planning'])
+
+  const plan = {
+    name: 'test plan',
+    due: '2019-12-01T00:00:00.056+10:00',
+    created: '2019-11-19T04:29:55.056+10:00',
+    note: 'just testing',
+  }
+  let createResp = await planning.call('plan', 'create_plan', { plan })
+  t.ok(createResp.plan && createResp.plan.id, 'plan created successfully')
+  await s.consistency()
+  const planId = createResp.plan.id
+
+  let readResp = await planning.call('plan', 'get_plan', { address: planId })
+  t.equal(readResp.plan.name, plan.name, 'Plan.name saved')
+  t.equal(readResp.plan.due, plan.due, 'Plan.due saved with original offset')
+  t.equal(readResp.plan.note, plan.note, 'Plan.note saved')
+
+  const updateResp = await planning.call('plan', 'update_plan', { plan: {
+    revisionId: readResp.plan.revisionId,
+    name: 'updated plan',
+    note: null,
+  } })
+  await s.consistency()
+  readResp = await planning.call('plan', 'get_plan', { address: planId })
+  t.equal(readResp.plan.name, 'updated plan', 'Plan.name updated')
+  t.equal(readResp.plan.note, undefined, 'Plan.note removed')
+  t.equal(readResp.plan.due, plan.due, 'Plan.due unchanged')
+
+  const deleteResp = await planning.call('plan', 'delete_plan', { address: updateResp.plan.revisionId })
+  t.ok(deleteResp, 'plan deleted successfully')
+  await s.consistency()
+
+  try {
+    await planning.call('plan', 'get_plan', { address: planId })
+  } catch (err) {
+    t.ok(err.data.data.includes('No entry at this address'), 'record deletion OK')
+  }
+})
+
+runner.registerScenario('plan links to processes & commitments', async (s, t) => {
+  const { cells: [planning, observation] } = await buildPlayer(s, config, ['planning', 'observation'])
+
+  const pResp = await planning.call('plan', 'create_plan', { plan: { name: 'linked plan' } })
+  t.ok(pResp.plan && pResp.plan.id, 'plan created successfully')
+  await s.consistency()
+  const planId = pResp.plan.id
+
+  const procResp = await observation.call('process', 'create_process', { process: {
+    name: 'planned process',
+    plannedWithin: planId,
+  } })
+  t.ok(procResp.process && procResp.process.id, 'process created successfully')
+  await s.consistency()
+  const processId = procResp.process.id
+
+  const demandResp = await planning.call('commitment', 'create_commitment', { commitment: {
+    note: 'independent demand',
+    plan: planId,
+    independentDemandOf: planId,
+    ...testCommitmentProps,
+  } })
+  t.ok(demandResp.commitment && demandResp.commitment.id, 'independent demand created successfully')
+  await s.consistency()
+  const demandId = demandResp.commitment.id
+
+  const processCommitmentResp = await planning.call('commitment', 'create_commitment', { commitment: {
+    note: 'commitment within a process of the plan',
+    plan: planId,
+    outputOf: processId,
+    ...testCommitmentProps,
+  } })
+  t.ok(processCommitmentResp.commitment && processCommitmentResp.commitment.id, 'process commitment created successfully')
+  await s.consistency()
+
+  // ASSERT: check plan link fields
+  let readResp = await planning.call('plan', 'get_plan', { address: planId })
+  t.deepEqual(readResp.plan.processes, [processId], 'Plan.processes indexed')
+  t.deepEqual(readResp.plan.independentDemands, [demandId], 'Plan.independentDemands indexed')
+  t.deepEqual(readResp.plan.nonProcessCommitments, [demandId], 'Plan.nonProcessCommitments excludes commitments within processes')
+
+  // ASSERT: check reciprocal query indexes
+  let queryResp = await planning.call('commitment_index', 'query_commitments', { params: { independentDemandOf: planId } })
+  t.deepEqual(queryResp && queryResp.results && queryResp.results.length, 1, 'independent demand query index present')
+  t.deepEqual(queryResp.results && queryResp.results[0] && queryResp.results[0].commitment && queryResp.results[0].commitment.id, demandId, 'independent demand query index created')
+
+  queryResp = await observation.call('process_index', 'query_processes', { params: { plannedWithin: planId } })
+  t.deepEqual(queryResp && queryResp.results && queryResp.results.length, 1, 'planned process query index present')
+  t.deepEqual(queryResp.results && queryResp.results[0] && queryResp.results[0].process && queryResp.results[0].process.id, processId, 'planned process query index created')
+
+  // SCENARIO: attempt to delete plan which is still referenced
+  try {
+    await planning.call('plan', 'delete_plan', { address: pResp.plan.revisionId })
+    t.fail('plan referenced by processes & commitments was deleted')
+  } catch (err) {
+    t.ok(err.data.data.includes('Record cannot be deleted'), 'deletion of referenced plan refused')
+  }
+
+  // SCENARIO: unlink records
+  await observation.call('process', 'update_process', { process: {
+    revisionId: procResp.process.revisionId,
+    plannedWithin: null,
+  } })
+  await planning.call('commitment', 'delete_commitment', { address: demandResp.commitment.revisionId })
+  await s.consistency()
+
+  readResp = await planning.call('plan', 'get_plan', { address: planId })
+  t.equal(readResp.plan.processes, undefined, 'Plan.processes reference removed')
+  t.equal(readResp.plan.independentDemands, undefined, 'Plan.independentDemands reference removed')
+  t.equal(readResp.plan.nonProcessCommitments, undefined, 'Plan.nonProcessCommitments reference removed')
+
+  const deleteResp = await planning.call('plan', 'delete_plan', { address: pResp.plan.revisionId })
+  t.ok(deleteResp, 'unreferenced plan deleted')
+})
+
+runner.run()
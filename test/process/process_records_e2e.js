@@ -3,6 +3,7 @@ const {
   buildRunner,
   buildPlayer,
   mockAgentId,
+  mockProfileId,
   mockIdentifier,
 } = require('../init')
 
@@ -208,5 +209,321 @@ runner2.registerScenario('process remote query indexes and relationships', async
   // TODO: modify
 })
 
+runner.registerScenario('process paired flow validation', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, buildConfig({ playerName: 'alice' }), ['observation'])
+
+  const pResp = await observation.call('process', 'create_process', { process: { name: 'repair process' } })
+  await s.consistency()
+  const processId = pResp.process.id
+
+  const acceptResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'accept',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+  t.ok(acceptResp.economicEvent, 'accept event created')
+
+  let checkResp = await observation.call('process', 'validate_process_flows', { address: processId })
+  t.equal(checkResp.finished, false, 'unfinished process is not checked')
+  t.equal(checkResp.warnings.length, 0, 'no warnings for unfinished process')
+
+  try {
+    await observation.call('process', 'update_process', { process: { revisionId: pResp.process.revisionId, finished: true } })
+    t.fail('process finished with unpaired flows')
+  } catch (err) {
+    t.ok(err.data.data.includes('unpaired flows'), 'unpaired accept input prevents finishing process')
+  }
+
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'modify',
+    outputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  const uResp = await observation.call('process', 'update_process', { process: { revisionId: pResp.process.revisionId, finished: true } })
+  await s.consistency()
+  t.equal(uResp.process.finished, true, 'process with paired flows can be finished')
+
+  checkResp = await observation.call('process', 'validate_process_flows', { address: processId })
+  t.equal(checkResp.finished, true, 'finished process is checked')
+  t.equal(checkResp.warnings.length, 0, 'accept input paired with modify output')
+
+  // SCENARIO: flows recorded after finishing are reported
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'accept',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  checkResp = await observation.call('process', 'validate_process_flows', { address: uResp.process.id })
+  t.equal(checkResp.warnings.length, 1, 'unpaired accept input recorded after finishing is reported')
+})
+
+runner2.registerScenario('process next & previous processes from shared resources', async (s, t) => {
+  const { cells: [observation, planning] } = await buildPlayer(s, buildConfig({ playerName: 'billy' }), ['observation', 'planning'])
+
+  const p1Resp = await observation.call('process', 'create_process', { process: { name: 'harvest' } })
+  const p2Resp = await observation.call('process', 'create_process', { process: { name: 'mill' } })
+  const p3Resp = await observation.call('process', 'create_process', { process: { name: 'bake' } })
+  await s.consistency()
+  const p1Id = p1Resp.process.id
+  const p2Id = p2Resp.process.id
+  const p3Id = p3Resp.process.id
+
+  // SCENARIO: observed flow of an inventoried resource from P1 into P2
+  const produceResp = await observation.call('economic_event', 'create_economic_event', {
+    event: { action: 'produce', outputOf: p1Id, ...testEventProps },
+    new_inventoried_resource: { name: 'wheat' },
+  })
+  await s.consistency()
+  const consumeEventResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'consume',
+    inputOf: p2Id,
+    resourceInventoriedAs: produceResp.economicResource.id,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  // SCENARIO: the container of an input is not itself an input
+  const p4Resp = await observation.call('process', 'create_process', { process: { name: 'pack' } })
+  const itemResp = await observation.call('economic_event', 'create_economic_event', {
+    event: { action: 'raise', ...testEventProps },
+    new_inventoried_resource: { name: 'sack' },
+  })
+  await s.consistency()
+  const p4Id = p4Resp.process.id
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'combine',
+    inputOf: p4Id,
+    resourceInventoriedAs: itemResp.economicResource.id,
+    toResourceInventoriedAs: produceResp.economicResource.id,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  // SCENARIO: planned flow of a resource specification from P2 into P3
+  const flourSpecId = mockIdentifier(false)
+  await planning.call('commitment', 'create_commitment', { commitment: {
+    action: 'produce',
+    outputOf: p2Id,
+    resourceConformsTo: flourSpecId,
+    ...testEventProps,
+  } })
+  const consumeResp = await planning.call('commitment', 'create_commitment', { commitment: {
+    action: 'consume',
+    inputOf: p3Id,
+    resourceConformsTo: flourSpecId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  let readResp = await observation.call('process', 'get_process', { address: p1Id })
+  t.deepEqual(readResp.process.nextProcesses, [p2Id], 'observed output resource links to next process')
+  t.deepEqual(readResp.process.previousProcesses, [], 'first process has no previous processes')
+
+  readResp = await observation.call('process', 'get_process', { address: p4Id })
+  t.deepEqual(readResp.process.previousProcesses, [], 'container resource of an input does not link to previous process')
+
+  readResp = await observation.call('process', 'get_process', { address: p2Id })
+  t.deepEqual(readResp.process.previousProcesses, [p1Id], 'observed input resource links to previous process')
+  t.deepEqual(readResp.process.nextProcesses, [p3Id], 'committed output specification links to next process')
+
+  readResp = await observation.call('process', 'get_process', { address: p3Id })
+  t.deepEqual(readResp.process.previousProcesses, [p2Id], 'committed input specification links to previous process')
+  t.deepEqual(readResp.process.nextProcesses, [], 'last process has no next processes')
+
+  // SCENARIO: removing the planned input disconnects P3
+  await planning.call('commitment', 'delete_commitment', { address: consumeResp.commitment.revisionId })
+  await s.consistency()
+
+  readResp = await observation.call('process', 'get_process', { address: p2Id })
+  t.deepEqual(readResp.process.nextProcesses, [], 'deleted commitment no longer links to next process')
+
+  readResp = await observation.call('process', 'get_process', { address: p3Id })
+  t.deepEqual(readResp.process.previousProcesses, [], 'deleted commitment no longer links to previous process')
+
+  // SCENARIO: removing the observed input disconnects P2 from P1
+  await observation.call('economic_event', 'delete_economic_event', { address: consumeEventResp.economicEvent.revisionId })
+  await s.consistency()
+
+  readResp = await observation.call('process', 'get_process', { address: p1Id })
+  t.deepEqual(readResp.process.nextProcesses, [], 'deleted event no longer links to next process')
+
+  readResp = await observation.call('process', 'get_process', { address: p2Id })
+  t.deepEqual(readResp.process.previousProcesses, [], 'deleted event no longer links to previous process')
+})
+
+runner2.registerScenario('process working agents from work events & commitments', async (s, t) => {
+  const { cells: [observation, planning] } = await buildPlayer(s, buildConfig({ playerName: 'billy' }), ['observation', 'planning'])
+
+  const pResp = await observation.call('process', 'create_process', { process: { name: 'assembly' } })
+  await s.consistency()
+  const processId = pResp.process.id
+
+  // working agents are read back from indexes, which always yield profile identifiers
+  const worker = mockProfileId(false)
+  const plannedWorker = mockProfileId(false)
+  const workProps = {
+    inputOf: processId,
+    receiver: mockAgentId(false),
+    resourceConformsTo: mockIdentifier(false),
+    effortQuantity: { hasNumericalValue: 2, hasUnit: mockIdentifier(false) },
+  }
+
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'work',
+    provider: worker,
+    hasPointInTime: '2019-11-19T12:12:42.739+01:00',
+    ...workProps,
+  } })
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'consume',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  const cResp = await planning.call('commitment', 'create_commitment', { commitment: {
+    action: 'work',
+    provider: plannedWorker,
+    due: '2019-11-19T12:12:42.739+01:00',
+    ...workProps,
+  } })
+  await s.consistency()
+
+  let readResp = await observation.call('process', 'get_process', { address: processId })
+  // index read order is not guaranteed, so compare as sorted sets
+  const sortedIds = ids => ids.map(([dna, hash]) => Buffer.concat([dna, hash]).toString('base64')).sort()
+  t.deepEqual(sortedIds(readResp.process.workingAgents), sortedIds([worker, plannedWorker]), 'providers of work events & commitments are working agents')
+
+  let workedResp = await observation.call('process_index', 'query_processes', { params: { workingAgents: worker } })
+  t.deepEqual(workedResp.results.map(r => r.process.id), [processId], 'observed worker indexed against process')
+  workedResp = await observation.call('process_index', 'query_processes', { params: { workingAgents: plannedWorker } })
+  t.deepEqual(workedResp.results.map(r => r.process.id), [processId], 'committed worker indexed against process')
+  workedResp = await observation.call('process_index', 'query_processes', { params: { workingAgents: testEventProps.provider } })
+  t.deepEqual(workedResp.results, [], 'providers of non-work inputs are not indexed')
+
+  await planning.call('commitment', 'delete_commitment', { address: cResp.commitment.revisionId })
+  await s.consistency()
+
+  readResp = await observation.call('process', 'get_process', { address: processId })
+  t.deepEqual(readResp.process.workingAgents, [worker], 'working agents updated when work commitment removed')
+  workedResp = await observation.call('process_index', 'query_processes', { params: { workingAgents: plannedWorker } })
+  t.deepEqual(workedResp.results, [], 'agent index cleared when work commitment removed')
+})
+
+runner2.registerScenario('process unplanned economic events', async (s, t) => {
+  const { cells: [observation, planning] } = await buildPlayer(s, buildConfig({ playerName: 'billy' }), ['observation', 'planning'])
+
+  const pResp = await observation.call('process', 'create_process', { process: { name: 'partly planned process' } })
+  await s.consistency()
+  const processId = pResp.process.id
+
+  const cResp = await planning.call('commitment', 'create_commitment', { commitment: {
+    action: 'consume',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+  const commitmentId = cResp.commitment.id
+
+  const plannedResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'consume',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  const unplannedResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'produce',
+    outputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+  const plannedEventId = plannedResp.economicEvent.id
+  const unplannedEventId = unplannedResp.economicEvent.id
+
+  let readResp = await observation.call('process', 'get_process', { address: processId })
+  t.deepEqual(readResp.process.unplannedEconomicEvents, [plannedEventId, unplannedEventId], 'events are unplanned until they fulfill a commitment')
+
+  await planning.call('fulfillment', 'create_fulfillment', { fulfillment: {
+    fulfills: commitmentId,
+    fulfilledBy: plannedEventId,
+  } })
+  await s.consistency()
+
+  readResp = await observation.call('process', 'get_process', { address: processId })
+  t.deepEqual(readResp.process.unplannedEconomicEvents, [unplannedEventId], 'events fulfilling commitments of the process are planned')
+})
+
+runner.registerScenario('process deletion is guarded by linked events', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, buildConfig({ playerName: 'alice' }), ['observation'])
+
+  const pResp = await observation.call('process', 'create_process', { process: { name: 'linked process' } })
+  const emptyResp = await observation.call('process', 'create_process', { process: { name: 'unlinked process' } })
+  await s.consistency()
+  const processId = pResp.process.id
+
+  t.equal(emptyResp.process.deletable, true, 'process without links is deletable')
+  t.equal(pResp.process.deletable, true, 'new process is deletable')
+
+  await observation.call('economic_event', 'create_economic_event', { event: {
+    action: 'consume',
+    inputOf: processId,
+    ...testEventProps,
+  } })
+  await s.consistency()
+
+  const readResp = await observation.call('process', 'get_process', { address: processId })
+  t.equal(readResp.process.deletable, false, 'process referenced by events is not deletable')
+
+  try {
+    await observation.call('process', 'delete_process', { address: pResp.process.revisionId })
+    t.fail('process referenced by events was deleted')
+  } catch (err) {
+    t.ok(err.data.data.includes('Record cannot be deleted'), 'deletion of referenced process refused')
+  }
+
+  const delResp = await observation.call('process', 'delete_process', { address: emptyResp.process.revisionId })
+  t.equal(delResp, true, 'unreferenced process deleted')
+})
+
+runner.registerScenario('process date validation', async (s, t) => {
+  const { cells: [observation] } = await buildPlayer(s, buildConfig({ playerName: 'alice' }), ['observation'])
+
+  try {
+    await observation.call('process', 'create_process', { process: {
+      name: 'backwards process',
+      hasBeginning: '2019-11-20T12:00:00.000+01:00',
+      hasEnd: '2019-11-19T12:00:00.000+01:00',
+    } })
+    t.fail('process ending before it began was created')
+  } catch (err) {
+    t.ok(err.data.data.includes('hasBeginning must not be later than hasEnd'), 'process beginning after end rejected')
+  }
+
+  try {
+    await observation.call('process', 'create_process', { process: {
+      name: 'impossible constraints',
+      after: '2019-11-20T12:00:00.000+01:00',
+      before: '2019-11-19T12:00:00.000+01:00',
+    } })
+    t.fail('process with inverted before / after constraints was created')
+  } catch (err) {
+    t.ok(err.data.data.includes('after must not be later than before'), 'process after later than before rejected')
+  }
+
+  const pResp = await observation.call('process', 'create_process', { process: {
+    name: 'scheduled process',
+    after: '2019-11-19T09:00:00.000+01:00',
+    hasBeginning: '2019-11-19T10:00:00.000+01:00',
+    hasEnd: '2019-11-19T11:00:00.000+01:00',
+    before: '2019-11-19T12:00:00.000+01:00',
+  } })
+  t.ok(pResp.process, 'process within its scheduling constraints created')
+  t.equal(pResp.process.hasBeginning, '2019-11-19T10:00:00+01:00', 'process dates retain their original offset')
+})
+
 runner.run()
 runner2.run()
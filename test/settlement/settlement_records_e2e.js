@@ -0,0 +1,131 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+  mockAgentId,
+  mockAddress,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+const testEventProps = {
+  resourceClassifiedAs: ['some-resource-type'],
+  resourceQuantity: { hasNumericalValue: 1, hasUnit: mockIdentifier(false) },
+  provider: mockAgentId(false),
+  receiver: mockAgentId(false),
+  hasPointInTime: '2019-11-19T04:29:55.056Z',
+}
+
+runner.registerScenario('claims are triggered by events and settled across DNAs', async (s, t) => {
+  const { cells: [planning, observation] } = await buildPlayer(s, config, ['planning', 'observation'])
+
+  // SCENARIO: write records
+  const deliveryResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    note: 'delivery which incurs an obligation to pay',
+    action: 'raise',
+    ...testEventProps,
+  } })
+  t.ok(deliveryResp.economicEvent && deliveryResp.economicEvent.id, 'triggering event created successfully')
+  await s.consistency()
+  const deliveryId = deliveryResp.economicEvent.id
+
+  const claim = {
+    action: 'transfer',
+    provider: testEventProps.receiver,
+    receiver: testEventProps.provider,
+    triggeredBy: deliveryId,
+    resourceClassifiedAs: ['currency'],
+    resourceQuantity: { hasNumericalValue: 10, hasUnit: mockIdentifier(false) },
+    due: '2019-12-01T00:00:00.056+10:00',
+    note: 'payment owed for delivery',
+  }
+  const claimResp = await planning.call('claim', 'create_claim', { claim })
+  t.ok(claimResp.claim && claimResp.claim.id, 'claim created successfully')
+  t.equal(claimResp.claim.finished, false, 'Claim.finished defaults to false')
+  await s.consistency()
+  const claimId = claimResp.claim.id
+
+  let readResponse = await planning.call('claim', 'get_claim', { address: claimId })
+  t.deepEqual(readResponse.claim.triggeredBy, deliveryId, 'Claim.triggeredBy reference saved')
+  t.equal(readResponse.claim.due, claim.due, 'Claim.due saved')
+  t.equal(readResponse.claim.note, claim.note, 'Claim.note saved')
+
+  const paymentResp = await observation.call('economic_event', 'create_economic_event', { event: {
+    note: 'payment settling the claim',
+    action: 'raise',
+    ...testEventProps,
+  } })
+  await s.consistency()
+  const paymentId = paymentResp.economicEvent.id
+
+  const settlement = {
+    settles: claimId,
+    settledBy: paymentId,
+    note: 'settlement indicating the relationship',
+  }
+  const settlementResp = await planning.call('settlement', 'create_settlement', { settlement })
+  t.ok(settlementResp.settlement && settlementResp.settlement.id, 'settlement created successfully')
+  await s.consistency()
+  const settlementId = settlementResp.settlement.id
+
+  // ASSERT: check settlement in originating network
+  readResponse = await planning.call('settlement', 'get_settlement', { address: settlementId })
+  t.deepEqual(readResponse.settlement.settledBy, paymentId, 'Settlement.settledBy reference saved')
+  t.deepEqual(readResponse.settlement.settles, claimId, 'Settlement.settles reference saved')
+
+  // ASSERT: check settlement in target network
+  readResponse = await observation.call('settlement', 'get_settlement', { address: settlementId })
+  t.deepEqual(readResponse.settlement.settledBy, paymentId, 'Settlement.settledBy reference replicated')
+  t.deepEqual(readResponse.settlement.settles, claimId, 'Settlement.settles reference replicated')
+
+  // ASSERT: check reciprocal links
+  readResponse = await planning.call('claim', 'get_claim', { address: claimId })
+  t.deepEqual(readResponse.claim.settledBy, [settlementId], 'Claim.settledBy reciprocal reference saved')
+
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: paymentId })
+  t.deepEqual(readResponse.economicEvent.settles, [settlementId], 'EconomicEvent.settles reciprocal reference saved')
+
+  // ASSERT: check query indexes
+  readResponse = await planning.call('settlement_index', 'query_settlements', { params: { settles: claimId } })
+  t.equal(readResponse.results.length, 1, 'read settlements by claim OK')
+  t.deepEqual(readResponse.results[0].settlement.id, settlementId, 'Settlement.settles indexed correctly')
+
+  readResponse = await observation.call('settlement_index', 'query_settlements', { params: { settledBy: paymentId } })
+  t.equal(readResponse.results.length, 1, 'read settlements by event OK')
+  t.deepEqual(readResponse.results[0].settlement.id, settlementId, 'Settlement.settledBy indexed correctly')
+
+  readResponse = await planning.call('claim_index', 'query_claims', { params: { settledBy: settlementId } })
+  t.equal(readResponse.results.length, 1, 'read claims by settlement OK')
+  t.deepEqual(readResponse.results[0].claim.id, claimId, 'Claim.settledBy indexed correctly')
+
+  // SCENARIO: remove the settlement
+  await planning.call('settlement', 'delete_settlement', { address: settlementResp.settlement.revisionId })
+  await s.consistency()
+
+  readResponse = await planning.call('claim', 'get_claim', { address: claimId })
+  t.equal(readResponse.claim.settledBy, undefined, 'Claim.settledBy reference removed')
+
+  readResponse = await observation.call('economic_event', 'get_economic_event', { address: paymentId })
+  t.equal(readResponse.economicEvent.settles, undefined, 'EconomicEvent.settles reference removed')
+})
+
+runner.registerScenario('claims must specify a quantity', async (s, t) => {
+  const { cells: [planning] } = await buildPlayer(s, config, ['planning'])
+
+  try {
+    await planning.call('claim', 'create_claim', { claim: {
+      action: 'transfer',
+      provider: mockAgentId(false),
+      receiver: mockAgentId(false),
+      triggeredBy: mockAddress(false),
+    } })
+    t.fail('claim created without a quantity')
+  } catch (err) {
+    t.ok(err.data.data.includes('Claim must include either a resource quantity or an effort quantity'), 'missing quantity rejected')
+  }
+})
+
+runner.run()
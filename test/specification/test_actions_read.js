@@ -21,7 +21,7 @@ runner.registerScenario('Built-in action API', async (s, t) => {
     }
   `, {})
 
-  t.equal(queryAllResp.data.actions.length, 18, 'all action builtins present')
+  t.equal(queryAllResp.data.actions.length, 20, 'all action builtins present')
 
   const getResp = await alice.graphQL(`
     query($id: ID!) {
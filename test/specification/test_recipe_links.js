@@ -0,0 +1,133 @@
+const {
+  buildConfig,
+  buildRunner,
+  buildPlayer,
+  mockIdentifier,
+} = require('../init')
+
+const runner = buildRunner()
+
+const config = buildConfig()
+
+const quantity = (hasNumericalValue) => ({ hasNumericalValue, hasUnit: mockIdentifier(false) })
+
+runner.registerScenario('recipe records link to each other & to specifications', async (s, t) => {
+  const { cells: [specification] } = await buildPlayer(s, config, ['specification'])
+
+  // SCENARIO: write specifications & recipe records
+  let resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'bread' } })
+  const breadSpecId = resp.resourceSpecification.id
+  resp = await specification.call('resource_specification', 'create_resource_specification', { resource_specification: { name: 'flour' } })
+  const flourSpecId = resp.resourceSpecification.id
+  resp = await specification.call('process_specification', 'create_process_specification', { process_specification: { name: 'bake' } })
+  const bakeSpecId = resp.processSpecification.id
+  await s.consistency()
+
+  resp = await specification.call('recipe_resource', 'create_recipe_resource', { recipe_resource: {
+    name: 'loaf of bread',
+    resourceConformsTo: breadSpecId,
+  } })
+  t.ok(resp.recipeResource && resp.recipeResource.id, 'recipe resource created')
+  t.equal(resp.recipeResource.substitutable, false, 'RecipeResource.substitutable defaults to false')
+  const breadResourceId = resp.recipeResource.id
+
+  resp = await specification.call('recipe_process', 'create_recipe_process', { recipe_process: {
+    name: 'bake bread',
+    processConformsTo: bakeSpecId,
+    hasDuration: { numericDuration: 2, unitType: 'hour' },
+  } })
+  t.ok(resp.recipeProcess && resp.recipeProcess.id, 'recipe process created')
+  t.deepEqual(resp.recipeProcess.hasDuration, { numericDuration: 2, unitType: 'hour' }, 'RecipeProcess.hasDuration saved')
+  const bakeProcessId = resp.recipeProcess.id
+
+  resp = await specification.call('recipe_exchange', 'create_recipe_exchange', { recipe_exchange: { name: 'bread sale' } })
+  t.ok(resp.recipeExchange && resp.recipeExchange.id, 'recipe exchange created')
+  const exchangeId = resp.recipeExchange.id
+  await s.consistency()
+
+  resp = await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'consume',
+    recipeInputOf: bakeProcessId,
+    resourceConformsTo: flourSpecId,
+    resourceQuantity: quantity(500),
+    stage: bakeSpecId,
+  } })
+  t.ok(resp.recipeFlow && resp.recipeFlow.id, 'recipe input flow created')
+  const inputFlowId = resp.recipeFlow.id
+
+  resp = await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'produce',
+    recipeOutputOf: bakeProcessId,
+    recipeFlowResource: breadResourceId,
+    resourceConformsTo: breadSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  t.ok(resp.recipeFlow && resp.recipeFlow.id, 'recipe output flow created')
+  const outputFlowId = resp.recipeFlow.id
+
+  resp = await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+    action: 'transfer',
+    recipeClauseOf: exchangeId,
+    resourceConformsTo: breadSpecId,
+    resourceQuantity: quantity(1),
+  } })
+  t.ok(resp.recipeFlow && resp.recipeFlow.id, 'recipe exchange flow created')
+  const clauseFlowId = resp.recipeFlow.id
+  await s.consistency()
+
+  // ASSERT: check link fields of related records
+  resp = await specification.call('recipe_process', 'get_recipe_process', { address: bakeProcessId })
+  t.deepEqual(resp.recipeProcess.recipeInputs, [inputFlowId], 'RecipeProcess.recipeInputs indexed')
+  t.deepEqual(resp.recipeProcess.recipeOutputs, [outputFlowId], 'RecipeProcess.recipeOutputs indexed')
+
+  resp = await specification.call('recipe_resource', 'get_recipe_resource', { address: breadResourceId })
+  t.deepEqual(resp.recipeResource.recipeFlows, [outputFlowId], 'RecipeResource.recipeFlows indexed')
+
+  resp = await specification.call('recipe_exchange', 'get_recipe_exchange', { address: exchangeId })
+  t.deepEqual(resp.recipeExchange.recipeClauses, [clauseFlowId], 'RecipeExchange.recipeClauses indexed')
+
+  // ASSERT: check recipe-level query indexes
+  resp = await specification.call('recipe_flow_index', 'query_recipe_flows', { params: { recipeOutputOf: bakeProcessId } })
+  t.equal(resp.results.length, 1, 'recipe flows queryable by output process')
+  t.equal(resp.results[0].recipeFlow.id, outputFlowId, 'recipe flow output query correct')
+
+  resp = await specification.call('recipe_flow_index', 'query_recipe_flows', { params: { resourceConformsTo: breadSpecId } })
+  t.equal(resp.results.length, 2, 'recipe flows queryable by resource specification')
+
+  resp = await specification.call('recipe_process_index', 'query_recipe_processes', { params: { recipeInputs: inputFlowId } })
+  t.equal(resp.results.length, 1, 'recipe processes queryable by input flow')
+  t.equal(resp.results[0].recipeProcess.id, bakeProcessId, 'recipe process input query correct')
+
+  // SCENARIO: unlink records
+  resp = await specification.call('recipe_flow', 'get_recipe_flow', { address: inputFlowId })
+  await specification.call('recipe_flow', 'delete_recipe_flow', { address: resp.recipeFlow.revisionId })
+  await s.consistency()
+
+  resp = await specification.call('recipe_process', 'get_recipe_process', { address: bakeProcessId })
+  t.equal(resp.recipeProcess.recipeInputs, undefined, 'RecipeProcess.recipeInputs reference removed on delete')
+})
+
+runner.registerScenario('recipe flow actions are validated against recipe processes', async (s, t) => {
+  const { cells: [specification] } = await buildPlayer(s, config, ['specification'])
+
+  try {
+    await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+      action: 'consume',
+      resourceQuantity: quantity(1),
+    } })
+    t.fail('input flow without a recipe process should be rejected')
+  } catch (err) {
+    t.ok(err.data.data.includes('input process required'), 'input flow requires recipeInputOf')
+  }
+
+  try {
+    await specification.call('recipe_flow', 'create_recipe_flow', { recipe_flow: {
+      action: 'not-an-action',
+    } })
+    t.fail('unknown action should be rejected')
+  } catch (err) {
+    t.ok(err.data.data.includes('Unknown action'), 'unknown actions rejected')
+  }
+})
+
+runner.run()
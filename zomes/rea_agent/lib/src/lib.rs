@@ -0,0 +1,169 @@
+/**
+ * Holo-REA agent zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Agent` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * `Person` records are bound to the public key of the agent who creates them,
+ * allowing the profile of any network participant to be looked up by key.
+ * `Organization` records are plain records identified by their own ID.
+ * Both kinds share the `AgentAddress` identifier, so either may be used as the
+ * `provider` or `receiver` of events, commitments and other flows.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    agent_info,
+    local_indexes::query_root_index,
+    identities::{
+        calculate_identity_address,
+        read_entry_identity_full,
+    },
+    links::get_linked_addresses,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+};
+
+use hc_zome_rea_agent_storage_consts::*;
+use hc_zome_rea_agent_storage::*;
+use hc_zome_rea_agent_rpc::*;
+
+pub fn handle_create_agent<S>(entry_def_id: S, agent: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let mut entry: EntryData = agent.into();
+
+    // Persons are bound to the key of the creating agent, who may only have one.
+    // :NOTE: this is best-effort only. It cannot be enforced in validation, since link lookups are not
+    // deterministic; concurrent creates (or those made before the key's links are visible) may yield
+    // multiple profiles, in which case `whois` / `whoami` return the first one found.
+    if entry.agent_type == AgentType::Person {
+        let my_pub_key = agent_info()?.agent_latest_pubkey;
+        if handle_whois(&entry_def_id, my_pub_key.to_owned())?.is_some() {
+            return Err(DataIntegrityError::InvalidRecord("agent already has a Person profile".to_string()));
+        }
+        entry.agent_pub_key = Some(my_pub_key);
+    }
+
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, entry)?;
+
+    if let Some(agent_pub_key) = &entry_resp.agent_pub_key {
+        let key_path = agent_key_path(agent_pub_key);
+        key_path.ensure()?;
+        create_link(
+            key_path.path_entry_hash()?,
+            calculate_identity_address(&entry_def_id, &base_address)?,
+            LinkTag::new(AGENT_KEY_PROFILE_LINK_TAG),
+        )?;
+    }
+
+    construct_response(&base_address, &revision_id, &entry_resp)
+}
+
+/// Read an agent profile.
+///
+/// Agents referenced by key in records written before agent profiles existed are resolved to
+/// their `Person` profile, if they have created one.
+///
+pub fn handle_get_agent<S>(entry_def_id: S, address: AgentAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    if let Some(agent_pub_key) = address.agent_pub_key() {
+        return handle_whois(entry_def_id, agent_pub_key)?
+            .ok_or(DataIntegrityError::EntryNotFound);
+    }
+
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry)
+}
+
+pub fn handle_update_agent<S>(entry_def_id: S, agent: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let revision_hash = agent.get_revision_id().clone();
+    let (revision_id, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &revision_hash, agent)?;
+    construct_response(&identity_address, &revision_id, &entry)
+}
+
+pub fn handle_delete_agent<S>(entry_def_id: S, revision_id: RevisionHash) -> RecordAPIResult<bool>
+    where S: AsRef<str>
+{
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // unbind the profile from its agent key, so that the agent may create another
+    if let Some(agent_pub_key) = &entry.agent_pub_key {
+        let identity = calculate_identity_address(&entry_def_id, &base_address)?;
+        let key_links = get_links(agent_key_path(agent_pub_key).path_entry_hash()?, Some(LinkTag::new(AGENT_KEY_PROFILE_LINK_TAG)))?;
+        for link in key_links.iter().filter(|link| link.target == identity) {
+            delete_link(link.create_link_hash.to_owned())?;
+        }
+    }
+
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+pub fn handle_get_all_agents<S>(entry_def_id: S) -> RecordAPIResult<Vec<ResponseData>>
+    where S: AsRef<str>
+{
+    query_root_index::<EntryData, EntryStorage, AgentAddress, _>(&entry_def_id)?
+        .into_iter()
+        .map(|result| {
+            let (revision_id, base_address, entry) = result?;
+            construct_response(&base_address, &revision_id, &entry)
+        })
+        .collect()
+}
+
+/// Read the `Person` profile of the calling agent, if they have created one
+pub fn handle_whoami<S>(entry_def_id: S) -> RecordAPIResult<Option<ResponseData>>
+    where S: AsRef<str>
+{
+    handle_whois(entry_def_id, agent_info()?.agent_latest_pubkey)
+}
+
+/// Read the `Person` profile bound to some agent's public key, if they have created one
+pub fn handle_whois<S>(entry_def_id: S, agent_pub_key: AgentPubKey) -> RecordAPIResult<Option<ResponseData>>
+    where S: AsRef<str>
+{
+    let profiles = get_linked_addresses(&agent_key_path(&agent_pub_key).path_entry_hash()?, LinkTag::new(AGENT_KEY_PROFILE_LINK_TAG))?;
+
+    match profiles.first() {
+        None => Ok(None),
+        Some(identity) => {
+            let address: AgentAddress = read_entry_identity_full(identity)?;
+            Ok(Some(handle_get_agent(entry_def_id, address)?))
+        },
+    }
+}
+
+/// Agent keys are indexed by their raw bytes, independently of any cell
+fn agent_key_path(agent_pub_key: &AgentPubKey) -> Path {
+    Path::from(vec![
+        AGENT_KEYS_ROOT_PATH.as_bytes().to_vec().into(),
+        agent_pub_key.get_raw_39().to_vec().into(),
+    ])
+}
+
+/// Create response from input DHT primitives
+fn construct_response(address: &AgentAddress, revision_id: &RevisionHash, e: &EntryData) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        agent: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            agent_type: e.agent_type.to_owned(),
+            name: e.name.to_owned(),
+            image: e.image.to_owned(),
+            classified_as: e.classified_as.to_owned(),
+            primary_location: e.primary_location.to_owned(),
+            note: e.note.to_owned(),
+            agent_pub_key: e.agent_pub_key.to_owned(),
+        }
+    })
+}
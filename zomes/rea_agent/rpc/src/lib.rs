@@ -0,0 +1,125 @@
+/**
+ * Holo-REA agent zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package Holo-REA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::{MaybeUndefined};
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    AgentPubKey,
+    ExternalURL,
+    LocationAddress,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+// Export external type interface to allow consuming zomes to easily import & define zome API
+pub use vf_attributes_hdk::{ AgentAddress };
+
+/// Distinguishes the two kinds of VF `Agent`.
+///
+/// A `Person` is bound to the public key of the Holochain agent who created it.
+/// An `Organization` has no key of its own and is identified only by its record ID.
+///
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AgentType {
+    Person,
+    Organization,
+}
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: AgentAddress,
+    pub revision_id: RevisionHash,
+    pub agent_type: AgentType,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ExternalURL>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classified_as: Option<Vec<ExternalURL>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_location: Option<LocationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_pub_key: Option<AgentPubKey>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub agent: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub agent_type: AgentType,
+    pub name: String,
+    #[serde(default)]
+    pub image: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub primary_location: MaybeUndefined<LocationAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+/// :NOTE: `agentType` cannot be changed once a record is created.
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: RevisionHash,
+    #[serde(default)]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    pub image: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub primary_location: MaybeUndefined<LocationAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &RevisionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- WHOIS REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WhoisParams {
+    pub agent_pub_key: AgentPubKey,
+}
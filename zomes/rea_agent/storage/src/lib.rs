@@ -0,0 +1,94 @@
+/**
+ * Holo-REA agent zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    AgentAddress,
+    AgentPubKey,
+    ExternalURL,
+    LocationAddress,
+};
+
+use hc_zome_rea_agent_rpc::{ AgentType, CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_agent_storage_consts::AGENT_ENTRY_TYPE;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub agent_type: AgentType,
+    pub name: String,
+    pub image: Option<ExternalURL>,
+    pub classified_as: Option<Vec<ExternalURL>>,
+    pub primary_location: Option<LocationAddress>,
+    pub note: Option<String>,
+    pub agent_pub_key: Option<AgentPubKey>,
+}
+
+impl EntryData {
+    /// Persons may only be authored by the agent they describe; Organizations have no key.
+    pub fn validate_agent_key(&self, author: &AgentPubKey) -> Result<(), String> {
+        match (&self.agent_type, &self.agent_pub_key) {
+            (AgentType::Person, Some(key)) if key == author => Ok(()),
+            (AgentType::Person, _) => Err("Person records may only be authored by the agent they describe".into()),
+            (AgentType::Organization, None) => Ok(()),
+            (AgentType::Organization, Some(_)) => Err("Organization records cannot be bound to an agent key".into()),
+        }
+    }
+}
+
+generate_record_entry!(EntryData, AgentAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+///
+/// :NOTE: `agent_pub_key` is assigned by the zome, never by the caller.
+///
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            agent_type: e.agent_type,
+            name: e.name,
+            image: e.image.into(),
+            classified_as: e.classified_as.into(),
+            primary_location: e.primary_location.into(),
+            note: e.note.into(),
+            agent_pub_key: None,
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            agent_type: self.agent_type.to_owned(),
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            image: if e.image == MaybeUndefined::Undefined { self.image.to_owned() } else { e.image.to_owned().into() },
+            classified_as: if e.classified_as == MaybeUndefined::Undefined { self.classified_as.to_owned() } else { e.classified_as.to_owned().into() },
+            primary_location: if e.primary_location == MaybeUndefined::Undefined { self.primary_location.to_owned() } else { e.primary_location.to_owned().into() },
+            note: if e.note == MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
+            agent_pub_key: self.agent_pub_key.to_owned(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const AGENT_ENTRY_TYPE: &str = "vf_agent";
+
+pub const AGENT_KEYS_ROOT_PATH: &str = "vf_agent_keys";
+pub const AGENT_KEY_PROFILE_LINK_TAG: &str = "agent_profile";
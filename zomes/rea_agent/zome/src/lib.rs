@@ -0,0 +1,104 @@
+/**
+ * Holo-REA agent zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_agent_rpc::*;
+use hc_zome_rea_agent_lib::*;
+use hc_zome_rea_agent_storage::*;
+
+#[hdk_extern]
+fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResult> {
+    let element = validation_data.element;
+    let author = element.header().author().to_owned();
+    let entry = element.into_inner().1;
+    let entry = match entry {
+        ElementEntry::Present(e) => e,
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+
+    match EntryStorage::try_from(&entry) {
+        Ok(agent_storage) => {
+            let record = agent_storage.entry();
+            record.validate_agent_key(&author)
+                .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
+                .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: AGENT_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 2.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateParams {
+    pub agent: CreateRequest,
+}
+
+#[hdk_extern]
+fn create_agent(CreateParams { agent }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_agent(AGENT_ENTRY_TYPE, agent)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadParams {
+    pub address: AgentAddress,
+}
+
+#[hdk_extern]
+fn get_agent(ReadParams { address }: ReadParams) -> ExternResult<ResponseData> {
+    Ok(handle_get_agent(AGENT_ENTRY_TYPE, address)?)
+}
+
+#[hdk_extern]
+fn get_all_agents(_: ()) -> ExternResult<Vec<ResponseData>> {
+    Ok(handle_get_all_agents(AGENT_ENTRY_TYPE)?)
+}
+
+#[hdk_extern]
+fn whoami(_: ()) -> ExternResult<Option<ResponseData>> {
+    Ok(handle_whoami(AGENT_ENTRY_TYPE)?)
+}
+
+#[hdk_extern]
+fn whois(WhoisParams { agent_pub_key }: WhoisParams) -> ExternResult<Option<ResponseData>> {
+    Ok(handle_whois(AGENT_ENTRY_TYPE, agent_pub_key)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateParams {
+    pub agent: UpdateRequest,
+}
+
+#[hdk_extern]
+fn update_agent(UpdateParams { agent }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_agent(AGENT_ENTRY_TYPE, agent)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeleteParams {
+    pub address: RevisionHash,
+}
+
+#[hdk_extern]
+fn delete_agent(DeleteParams { address }: DeleteParams) -> ExternResult<bool> {
+    Ok(handle_delete_agent(AGENT_ENTRY_TYPE, address)?)
+}
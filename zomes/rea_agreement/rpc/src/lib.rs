@@ -14,8 +14,7 @@ pub use vf_attributes_hdk::{
     AgreementAddress,
     CommitmentAddress,
     EconomicEventAddress,
-    DateTime,
-    FixedOffset,
+    DateTimeValue,
 };
 
 //---------------- EXTERNAL RECORD STRUCTURE ----------------
@@ -30,7 +29,7 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<DateTime<FixedOffset>>,
+    pub created: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     #[serde(default)]
@@ -63,7 +62,7 @@ pub struct CreateRequest {
     pub name: MaybeUndefined<String>,
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub created: MaybeUndefined<DateTime<FixedOffset>>,
+    pub created: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub note: MaybeUndefined<String>,
@@ -86,7 +85,7 @@ pub struct UpdateRequest {
     pub name: MaybeUndefined<String>,
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub created: MaybeUndefined<DateTime<FixedOffset>>,
+    pub created: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub note: MaybeUndefined<String>,
@@ -14,8 +14,7 @@ use hdk_records::{
 };
 
 use vf_attributes_hdk::{
-    DateTime,
-    FixedOffset,
+    DateTimeValue,
 };
 
 use hc_zome_rea_agreement_rpc::{ CreateRequest, UpdateRequest };
@@ -44,7 +43,7 @@ pub struct AgreementZomeConfig {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
 pub struct EntryData {
     pub name: Option<String>,
-    pub created: Option<DateTime<FixedOffset>>,
+    pub created: Option<DateTimeValue>,
     pub note: Option<String>,
 }
 
@@ -0,0 +1,131 @@
+/**
+ * Holo-REA appreciation zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Appreciation` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    MaybeUndefined,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_appreciation_storage::*;
+use hc_zome_rea_appreciation_rpc::*;
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData,
+    EntryStorage as EventStorage,
+};
+
+pub use hc_zome_rea_appreciation_storage::APPRECIATION_ENTRY_TYPE;
+
+pub fn handle_create_appreciation<S>(entry_def_id: S, event_entry_def_id: S, appreciation: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    validate_event_exists(&event_entry_def_id, "appreciationOf", appreciation.get_appreciation_of())?;
+    validate_event_exists(&event_entry_def_id, "appreciationWith", appreciation.get_appreciation_with())?;
+
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, appreciation.to_owned())?;
+
+    // link entries in the local DNA
+    create_index!(Local(appreciation.appreciation_of(appreciation.get_appreciation_of()), economic_event.appreciated_by(&base_address)))?;
+    create_index!(Local(appreciation.appreciation_with(appreciation.get_appreciation_with()), economic_event.appreciation_of(&base_address)))?;
+
+    construct_response(&base_address, &revision_id, &entry_resp)
+}
+
+pub fn handle_get_appreciation<S>(entry_def_id: S, address: AppreciationAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry)
+}
+
+pub fn handle_update_appreciation<S>(entry_def_id: S, event_entry_def_id: S, appreciation: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    if let MaybeUndefined::Some(appreciation_of) = &appreciation.appreciation_of {
+        validate_event_exists(&event_entry_def_id, "appreciationOf", appreciation_of)?;
+    }
+    if let MaybeUndefined::Some(appreciation_with) = &appreciation.appreciation_with {
+        validate_event_exists(&event_entry_def_id, "appreciationWith", appreciation_with)?;
+    }
+
+    let (revision_id, base_address, new_entry, prev_entry): (_, AppreciationAddress, EntryData, EntryData) = update_record(&entry_def_id, &appreciation.get_revision_id(), appreciation.to_owned())?;
+
+    if new_entry.appreciation_of != prev_entry.appreciation_of {
+        update_index!(Local(
+            appreciation
+                .appreciation_of(&vec![new_entry.appreciation_of.clone()])
+                .not(&vec![prev_entry.appreciation_of]),
+            economic_event.appreciated_by(&base_address)
+        ))?;
+    }
+    if new_entry.appreciation_with != prev_entry.appreciation_with {
+        update_index!(Local(
+            appreciation
+                .appreciation_with(&vec![new_entry.appreciation_with.clone()])
+                .not(&vec![prev_entry.appreciation_with]),
+            economic_event.appreciation_of(&base_address)
+        ))?;
+    }
+
+    construct_response(&base_address, &revision_id, &new_entry)
+}
+
+pub fn handle_delete_appreciation(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    // read any referencing indexes
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    update_index!(Local(appreciation.appreciation_of.not(&vec![entry.appreciation_of]), economic_event.appreciated_by(&base_address)))?;
+    update_index!(Local(appreciation.appreciation_with.not(&vec![entry.appreciation_with]), economic_event.appreciation_of(&base_address)))?;
+
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Ensure that an `EconomicEvent` referenced by an `Appreciation` exists in this DNA
+fn validate_event_exists<S>(event_entry_def_id: S, field_name: &str, event: &EconomicEventAddress) -> RecordAPIResult<()>
+    where S: AsRef<str>
+{
+    match read_record_entry::<EventData, EventStorage, _,_>(&event_entry_def_id, event.as_ref()) {
+        Ok(_) => Ok(()),
+        Err(DataIntegrityError::IndexNotFound(_)) | Err(DataIntegrityError::EntryNotFound) =>
+            Err(DataIntegrityError::InvalidRecord(format!("Appreciation.{} must reference an existing EconomicEvent", field_name))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Create response from input DHT primitives
+fn construct_response(address: &AppreciationAddress, revision_id: &RevisionHash, e: &EntryData) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        appreciation: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            appreciation_of: e.appreciation_of.to_owned(),
+            appreciation_with: e.appreciation_with.to_owned(),
+            note: e.note.to_owned(),
+        }
+    })
+}
+
+/// Properties accessor for zome config.
+fn read_economic_event_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.appreciation.economic_event_index_zome)
+}
+
+/// Properties accessor for zome config.
+fn read_appreciation_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.appreciation.index_zome)
+}
@@ -0,0 +1,82 @@
+/**
+ * Holo-REA appreciation zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    AppreciationAddress,
+    EconomicEventAddress,
+};
+
+use hc_zome_rea_appreciation_rpc::{ CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_appreciation_storage_consts::APPRECIATION_ENTRY_TYPE;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub appreciation: AppreciationZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct AppreciationZomeConfig {
+    pub economic_event_index_zome: String,
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub appreciation_of: EconomicEventAddress,
+    pub appreciation_with: EconomicEventAddress,
+    pub note: Option<String>,
+}
+
+generate_record_entry!(EntryData, AppreciationAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            appreciation_of: e.appreciation_of.into(),
+            appreciation_with: e.appreciation_with.into(),
+            note: e.note.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            appreciation_of: match &e.appreciation_of {
+                MaybeUndefined::Some(appreciation_of) => appreciation_of.clone(),
+                _ => self.appreciation_of.clone(),
+            },
+            appreciation_with: match &e.appreciation_with {
+                MaybeUndefined::Some(appreciation_with) => appreciation_with.clone(),
+                _ => self.appreciation_with.clone(),
+            },
+            note: if e.note == MaybeUndefined::Undefined { self.note.clone() } else { e.note.clone().into() },
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const APPRECIATION_ENTRY_TYPE: &str = "vf_appreciation";
+pub const APPRECIATION_APPRECIATION_OF_LINK_TAG: &str = "appreciation_of";
+pub const APPRECIATION_APPRECIATION_WITH_LINK_TAG: &str = "appreciation_with";
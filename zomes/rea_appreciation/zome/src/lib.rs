@@ -0,0 +1,106 @@
+/**
+ * Holo-REA appreciation zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_appreciation_rpc::*;
+use hc_zome_rea_appreciation_lib::*;
+use hc_zome_rea_appreciation_storage::*;
+use hc_zome_rea_economic_event_storage_consts::EVENT_ENTRY_TYPE;
+use hc_zome_rea_economic_event_storage::EntryStorage as EventStorage;
+
+#[hdk_extern]
+fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResult> {
+    let element = validation_data.element;
+    let entry = element.into_inner().1;
+    let entry = match entry {
+        ElementEntry::Present(e) => e,
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+
+    match EntryStorage::try_from(&entry) {
+        Ok(appreciation_storage) => {
+            let record = appreciation_storage.entry();
+            match validate_event_exists("appreciation_of", &record.appreciation_of)? {
+                ValidateCallbackResult::Valid => validate_event_exists("appreciation_with", &record.appreciation_with),
+                invalid => Ok(invalid),
+            }
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+/// Ensure that an `EconomicEvent` referenced by an `Appreciation` exists.
+///
+/// An event which cannot yet be retrieved is returned to the host as an error rather than
+/// marking the `Appreciation` invalid, so that validation is retried once it is available.
+///
+fn validate_event_exists(field_name: &str, event: &EconomicEventAddress) -> ExternResult<ValidateCallbackResult> {
+    let event_hash: &EntryHash = event.as_ref();
+    let event_entry = must_get_entry(event_hash.to_owned())?;
+
+    Ok(match EventStorage::try_from(event_entry.as_content()) {
+        Ok(_) => ValidateCallbackResult::Valid,
+        Err(_) => ValidateCallbackResult::Invalid(format!("Appreciation.{} must reference an existing EconomicEvent", field_name)),
+    })
+}
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: APPRECIATION_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 2.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateParams {
+    pub appreciation: CreateRequest,
+}
+
+#[hdk_extern]
+fn create_appreciation(CreateParams { appreciation }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_appreciation(APPRECIATION_ENTRY_TYPE, EVENT_ENTRY_TYPE, appreciation)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadParams {
+    pub address: AppreciationAddress,
+}
+
+#[hdk_extern]
+fn get_appreciation(ReadParams { address }: ReadParams) -> ExternResult<ResponseData> {
+    Ok(handle_get_appreciation(APPRECIATION_ENTRY_TYPE, address)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateParams {
+    pub appreciation: UpdateRequest,
+}
+
+#[hdk_extern]
+fn update_appreciation(UpdateParams { appreciation }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_appreciation(APPRECIATION_ENTRY_TYPE, EVENT_ENTRY_TYPE, appreciation)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeleteParams {
+    pub address: RevisionHash,
+}
+
+#[hdk_extern]
+fn delete_appreciation(DeleteParams { address }: DeleteParams) -> ExternResult<bool> {
+    Ok(handle_delete_appreciation(address)?)
+}
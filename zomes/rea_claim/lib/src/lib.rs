@@ -0,0 +1,90 @@
+/**
+ * Holo-REA claim zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Claim` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_claim_storage::*;
+use hc_zome_rea_claim_rpc::*;
+
+pub use hc_zome_rea_claim_storage::CLAIM_ENTRY_TYPE;
+
+pub fn handle_create_claim<S>(entry_def_id: S, claim: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, claim)?;
+    construct_response(&base_address, &revision_id, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_claim<S>(entry_def_id: S, address: ClaimAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_update_claim<S>(entry_def_id: S, claim: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let revision_hash = claim.get_revision_id().clone();
+    let (revision_id, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &revision_hash, claim)?;
+    construct_response(&identity_address, &revision_id, &entry, get_link_fields(&identity_address)?)
+}
+
+pub fn handle_delete_claim(revision_id: RevisionHash) -> RecordAPIResult<bool> {
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &ClaimAddress, revision_id: &RevisionHash, e: &EntryData,
+    settlements: Vec<SettlementAddress>,
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        claim: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            action: e.action.to_owned(),
+            provider: e.provider.to_owned(),
+            receiver: e.receiver.to_owned(),
+            triggered_by: e.triggered_by.to_owned(),
+            resource_classified_as: e.resource_classified_as.to_owned(),
+            resource_conforms_to: e.resource_conforms_to.to_owned(),
+            resource_quantity: e.resource_quantity.to_owned(),
+            effort_quantity: e.effort_quantity.to_owned(),
+            due: e.due.to_owned(),
+            created: e.created.to_owned(),
+            agreed_in: e.agreed_in.to_owned(),
+            note: e.note.to_owned(),
+            in_scope_of: e.in_scope_of.to_owned(),
+            finished: e.finished.to_owned(),
+            settled_by: settlements.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_claim_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.claim.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &ClaimAddress) -> RecordAPIResult<Vec<SettlementAddress>> {
+    Ok(read_index!(claim(base_address).settled_by)?)
+}
@@ -0,0 +1,166 @@
+/**
+ * Holo-REA claim zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package Holo-REA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::{MaybeUndefined, default_false};
+use vf_measurement::QuantityValue;
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    ActionId,
+    DateTimeValue,
+    ExternalURL,
+    AgentAddress,
+    ResourceSpecificationAddress,
+    EconomicEventAddress,
+    SettlementAddress,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+// Export external type interface to allow consuming zomes to easily import & define zome API
+pub use vf_attributes_hdk::{ ClaimAddress };
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: ClaimAddress,
+    pub revision_id: RevisionHash,
+    pub action: ActionId,
+    pub provider: AgentAddress,
+    pub receiver: AgentAddress,
+    pub triggered_by: EconomicEventAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_classified_as: Option<Vec<ExternalURL>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTimeValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTimeValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agreed_in: Option<ExternalURL>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_scope_of: Option<Vec<String>>,
+
+    pub finished: bool,
+
+    // LINK FIELDS
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub settled_by: Vec<SettlementAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub claim: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub action: ActionId,
+    pub provider: AgentAddress,
+    pub receiver: AgentAddress,
+    pub triggered_by: EconomicEventAddress,
+    #[serde(default)]
+    pub resource_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub due: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    pub created: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    pub agreed_in: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+    #[serde(default = "default_false")]
+    pub finished: MaybeUndefined<bool>,
+    #[serde(default)]
+    pub in_scope_of: MaybeUndefined<Vec<String>>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: RevisionHash,
+    #[serde(default)]
+    pub action: MaybeUndefined<ActionId>,
+    #[serde(default)]
+    pub provider: MaybeUndefined<AgentAddress>,
+    #[serde(default)]
+    pub receiver: MaybeUndefined<AgentAddress>,
+    #[serde(default)]
+    pub triggered_by: MaybeUndefined<EconomicEventAddress>,
+    #[serde(default)]
+    pub resource_classified_as: MaybeUndefined<Vec<ExternalURL>>,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub due: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    pub created: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    pub agreed_in: MaybeUndefined<ExternalURL>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+    #[serde(default)]
+    pub finished: MaybeUndefined<bool>,
+    #[serde(default)]
+    pub in_scope_of: MaybeUndefined<Vec<String>>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &RevisionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub settled_by: Option<SettlementAddress>,
+}
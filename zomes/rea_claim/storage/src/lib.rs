@@ -0,0 +1,126 @@
+/**
+ * Holo-REA claim zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+use vf_measurement::QuantityValue;
+
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    ActionId,
+    DateTimeValue,
+    ExternalURL,
+    ClaimAddress,
+    AgentAddress,
+    ResourceSpecificationAddress,
+    EconomicEventAddress,
+};
+
+use hc_zome_rea_claim_rpc::{ CreateRequest, UpdateRequest };
+
+pub use hc_zome_rea_claim_storage_consts::CLAIM_ENTRY_TYPE;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub claim: ClaimZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct ClaimZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub action: ActionId,
+    pub provider: AgentAddress,
+    pub receiver: AgentAddress,
+    pub triggered_by: EconomicEventAddress,
+    pub resource_classified_as: Option<Vec<ExternalURL>>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    pub resource_quantity: Option<QuantityValue>,
+    pub effort_quantity: Option<QuantityValue>,
+    pub due: Option<DateTimeValue>,
+    pub created: Option<DateTimeValue>,
+    pub agreed_in: Option<ExternalURL>,
+    pub note: Option<String>,
+    pub finished: bool,
+    pub in_scope_of: Option<Vec<String>>,
+}
+
+impl EntryData {
+    pub fn validate_or_fields(&self) -> Result<(), String> {
+        if !(self.resource_quantity.is_some() || self.effort_quantity.is_some()) {
+            return Err("Claim must include either a resource quantity or an effort quantity".into());
+        }
+        Ok(())
+    }
+}
+
+generate_record_entry!(EntryData, ClaimAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            action: e.action.to_owned(),
+            provider: e.provider.into(),
+            receiver: e.receiver.into(),
+            triggered_by: e.triggered_by.into(),
+            resource_classified_as: e.resource_classified_as.into(),
+            resource_conforms_to: e.resource_conforms_to.into(),
+            resource_quantity: e.resource_quantity.into(),
+            effort_quantity: e.effort_quantity.into(),
+            due: e.due.into(),
+            created: e.created.into(),
+            agreed_in: e.agreed_in.into(),
+            note: e.note.into(),
+            finished: e.finished.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_false" binding via Serde
+            in_scope_of: e.in_scope_of.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            action: if !e.action.is_some() { self.action.to_owned() } else { e.action.to_owned().unwrap() },
+            provider: if !e.provider.is_some() { self.provider.to_owned() } else { e.provider.to_owned().unwrap() },
+            receiver: if !e.receiver.is_some() { self.receiver.to_owned() } else { e.receiver.to_owned().unwrap() },
+            triggered_by: if !e.triggered_by.is_some() { self.triggered_by.to_owned() } else { e.triggered_by.to_owned().unwrap() },
+            resource_classified_as: if e.resource_classified_as == MaybeUndefined::Undefined { self.resource_classified_as.to_owned() } else { e.resource_classified_as.to_owned().into() },
+            resource_conforms_to: if e.resource_conforms_to == MaybeUndefined::Undefined { self.resource_conforms_to.to_owned() } else { e.resource_conforms_to.to_owned().into() },
+            resource_quantity: if e.resource_quantity == MaybeUndefined::Undefined { self.resource_quantity.to_owned() } else { e.resource_quantity.to_owned().into() },
+            effort_quantity: if e.effort_quantity == MaybeUndefined::Undefined { self.effort_quantity.to_owned() } else { e.effort_quantity.to_owned().into() },
+            due: if e.due == MaybeUndefined::Undefined { self.due.to_owned() } else { e.due.to_owned().into() },
+            created: if e.created == MaybeUndefined::Undefined { self.created.to_owned() } else { e.created.to_owned().into() },
+            agreed_in: if e.agreed_in == MaybeUndefined::Undefined { self.agreed_in.to_owned() } else { e.agreed_in.to_owned().into() },
+            note: if e.note == MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
+            finished: if e.finished == MaybeUndefined::Undefined { self.finished.to_owned() } else { e.finished.to_owned().to_option().unwrap() },
+            in_scope_of: if e.in_scope_of == MaybeUndefined::Undefined { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
+        }
+    }
+}
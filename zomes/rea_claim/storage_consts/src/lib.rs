@@ -0,0 +1,11 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const CLAIM_ENTRY_TYPE: &str = "vf_claim";
+pub const CLAIM_SETTLEDBY_LINK_TAG: &str = "settled_by";
+
+pub const CLAIM_SETTLEDBY_READ_API_METHOD: &str = "_internal_read_claim_settled_by";
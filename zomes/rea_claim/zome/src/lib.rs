@@ -0,0 +1,88 @@
+/**
+ * Holo-REA claim zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_claim_rpc::*;
+use hc_zome_rea_claim_lib::*;
+use hc_zome_rea_claim_storage::*;
+
+#[hdk_extern]
+fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResult> {
+    let element = validation_data.element;
+    let entry = element.into_inner().1;
+    let entry = match entry {
+        ElementEntry::Present(e) => e,
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+
+    match EntryStorage::try_from(&entry) {
+        Ok(claim_storage) => {
+            let record = claim_storage.entry();
+            record.validate_or_fields()
+                .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
+                .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: CLAIM_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 2.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateParams {
+    pub claim: CreateRequest,
+}
+
+#[hdk_extern]
+fn create_claim(CreateParams { claim }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_claim(CLAIM_ENTRY_TYPE, claim)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReadParams {
+    pub address: ClaimAddress,
+}
+
+#[hdk_extern]
+fn get_claim(ReadParams { address }: ReadParams) -> ExternResult<ResponseData> {
+    Ok(handle_get_claim(CLAIM_ENTRY_TYPE, address)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateParams {
+    pub claim: UpdateRequest,
+}
+
+#[hdk_extern]
+fn update_claim(UpdateParams { claim }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_claim(CLAIM_ENTRY_TYPE, claim)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeleteParams {
+    pub address: RevisionHash,
+}
+
+#[hdk_extern]
+fn delete_claim(DeleteParams { address }: DeleteParams) -> ExternResult<bool> {
+    Ok(handle_delete_claim(address)?)
+}
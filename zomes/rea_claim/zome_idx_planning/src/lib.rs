@@ -0,0 +1,12 @@
+/**
+ * Claim query indexes for planning DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_claim_rpc::*;
+
+#[index_zome]
+struct Claim {
+    settled_by: Local<settlement, settles>,
+}
@@ -7,20 +7,42 @@
  * @package Holo-REA
  */
 use paste::paste;
+use hdk::prelude::{Serialize, Deserialize};
 use hdk_records::{
-    RecordAPIResult, MaybeUndefined,
+    RecordAPIResult, OtherCellResult, MaybeUndefined,
     records::{
         create_record,
+        read_existing_record,
+        create_idempotency_key_index,
         read_record_entry,
         read_record_entry_by_header,
         update_record,
         delete_record,
     },
+    rpc::{
+        call_zome_method,
+        call_local_zome_method,
+    },
 };
 use hdk_semantic_indexes_client_lib::*;
 
+use hc_zome_rea_commitment_storage_consts::*;
 use hc_zome_rea_commitment_storage::*;
 use hc_zome_rea_commitment_rpc::*;
+use hc_zome_rea_commitment_rpc::{
+    ResponseData as CommitmentResponse,
+    QueryParams as CommitmentQueryParams,
+};
+use hc_zome_rea_intent_rpc::{
+    ResponseData as IntentResponse,
+    QueryParams as IntentQueryParams,
+};
+use hc_zome_rea_process_rpc::{
+    IndexFlowsParams,
+    FlowsUpdate,
+    PlannedFlow,
+    PlannedFlowAddress,
+};
 
 // :SHONK: needed to re-export for zome `entry_defs()` where macro-assigned defs are overridden
 pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
@@ -28,18 +50,33 @@ pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
 pub fn handle_create_commitment<S>(entry_def_id: S, commitment: CreateRequest) -> RecordAPIResult<ResponseData>
     where S: AsRef<str>
 {
+    // if a record has already been created with this idempotency key (eg. a retried request), return it without re-indexing
+    let idempotency_key: Option<String> = commitment.idempotency_key.to_owned().into();
+    if let Some((header_addr, base_address, entry_resp)) = read_existing_record::<EntryData, EntryStorage, CommitmentAddress, _>(&entry_def_id, &idempotency_key)? {
+        return construct_response(&base_address, &header_addr, &entry_resp, get_link_fields(&base_address)?);
+    }
+
     let (header_addr, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, commitment.to_owned())?;
+    create_idempotency_key_index(&entry_def_id, &idempotency_key, &base_address)?;
 
     // handle link fields
     if let CreateRequest { input_of: MaybeUndefined::Some(input_of), .. } = &commitment {
         create_index!(Remote(commitment.input_of(input_of), process.committed_inputs(&base_address)))?;
+        let _ = request_index_process_flows(input_of, &base_address, Some(&entry_resp))?;
     };
     if let CreateRequest { output_of: MaybeUndefined::Some(output_of), .. } = &commitment {
         create_index!(Remote(commitment.output_of(output_of), process.committed_outputs(&base_address)))?;
+        let _ = request_index_process_flows(output_of, &base_address, Some(&entry_resp))?;
     };
     if let CreateRequest { clause_of: MaybeUndefined::Some(clause_of), .. } = &commitment {
         create_index!(Remote(commitment.clause_of(clause_of), agreement.commitments(&base_address)))?;
     };
+    if let Some(independent_demand_of) = &entry_resp.independent_demand_of {
+        create_index!(Local(commitment.independent_demand_of(independent_demand_of), plan.independent_demands(&base_address)))?;
+    };
+    if let Some(planned_within) = &entry_resp.planned_within() {
+        create_index!(Local(commitment.planned_within(planned_within), plan.non_process_commitments(&base_address)))?;
+    };
 
     // :TODO: pass results from link creation rather than re-reading
     construct_response(&base_address, &header_addr, &entry_resp, get_link_fields(&base_address)?)
@@ -89,6 +126,46 @@ pub fn handle_update_commitment<S>(entry_def_id: S, commitment: UpdateRequest) -
             agreement.commitments(&base_address)
         ))?;
     }
+    if new_entry.independent_demand_of != prev_entry.independent_demand_of {
+        let new_value = match &new_entry.independent_demand_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.independent_demand_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            commitment
+                .independent_demand_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            plan.independent_demands(&base_address)
+        ))?;
+    }
+    if new_entry.planned_within() != prev_entry.planned_within() {
+        let new_value = match &new_entry.planned_within() { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.planned_within() { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            commitment
+                .planned_within(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            plan.non_process_commitments(&base_address)
+        ))?;
+    }
+    if new_entry.input_of != prev_entry.input_of
+        || new_entry.output_of != prev_entry.output_of
+        || new_entry.action != prev_entry.action
+        || new_entry.provider != prev_entry.provider
+        || new_entry.resource_inventoried_as != prev_entry.resource_inventoried_as
+        || new_entry.resource_conforms_to != prev_entry.resource_conforms_to
+    {
+        // reindex the Processes this commitment has left as well as those it now belongs to
+        let mut processes: Vec<ProcessAddress> = vec![];
+        let all_processes = prev_entry.input_of.iter().chain(prev_entry.output_of.iter())
+            .chain(new_entry.input_of.iter()).chain(new_entry.output_of.iter());
+        for process_address in all_processes {
+            if !processes.contains(process_address) {
+                processes.push(process_address.to_owned());
+            }
+        }
+        for process_address in processes.iter() {
+            let _ = request_index_process_flows(process_address, &base_address, Some(&new_entry))?;
+        }
+    }
 
     construct_response(&base_address, &revision_id, &new_entry, get_link_fields(&base_address)?)
 }
@@ -97,6 +174,8 @@ pub fn handle_delete_commitment(revision_id: RevisionHash) -> RecordAPIResult<bo
 {
     // load the record to ensure it is of the correct type
     let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+    let flow_processes: Vec<ProcessAddress> = entry.input_of.iter().chain(entry.output_of.iter()).cloned().collect();
+    let planned_within = entry.planned_within();
 
     // handle link fields
     if let Some(process_address) = entry.input_of {
@@ -108,9 +187,142 @@ pub fn handle_delete_commitment(revision_id: RevisionHash) -> RecordAPIResult<bo
     if let Some(agreement_address) = entry.clause_of {
         update_index!(Remote(commitment.clause_of.not(&vec![agreement_address]), agreement.commitments(&base_address)))?;
     }
+    if let Some(plan_address) = entry.independent_demand_of {
+        update_index!(Local(commitment.independent_demand_of.not(&vec![plan_address]), plan.independent_demands(&base_address)))?;
+    }
+    if let Some(plan_address) = planned_within {
+        update_index!(Local(commitment.planned_within.not(&vec![plan_address]), plan.non_process_commitments(&base_address)))?;
+    }
 
     // delete entry last, as it must be present in order for links to be removed
-    delete_record::<EntryStorage, _>(&revision_id)
+    let deleted = delete_record::<EntryStorage, _>(&revision_id)?;
+
+    for process_address in flow_processes.iter() {
+        let _ = request_index_process_flows(process_address, &base_address, None)?;
+    }
+
+    Ok(deleted)
+}
+
+/// Notify the (possibly remote) Process zome that the planned inputs or outputs of a Process have changed.
+///
+/// All Commitments & Intents which are inputs or outputs of the Process are read from this DNA and sent
+/// along, so that the Process zome does not need to call back into planning. `current` holds the values
+/// of the Commitment `commitment` as written in this call, which cannot be read back until this call's writes are committed.
+///
+/// As with other indexes spanning DNAs (@see `create_remote_index`), failure to reach the Process's DNA
+/// does not fail the write, and is returned for the caller to ignore or report. Failures to read from this
+/// DNA are errors. The Process recomputes its indexes from all of its planned flows each time, so a missed
+/// notification is corrected by the next change to any planned flow of the same Process.
+///
+fn request_index_process_flows(process: &ProcessAddress, commitment: &CommitmentAddress, current: Option<&EntryData>) -> RecordAPIResult<OtherCellResult<()>>
+{
+    let (mut inputs, mut outputs) = read_planned_flows(process)?;
+
+    let flow_address = PlannedFlowAddress::Commitment(commitment.to_owned());
+    inputs.retain(|flow| flow.address != flow_address);
+    outputs.retain(|flow| flow.address != flow_address);
+    if let Some(entry) = current {
+        if entry.input_of.as_ref() == Some(process) {
+            inputs.push(planned_flow(commitment, entry));
+        }
+        if entry.output_of.as_ref() == Some(process) {
+            outputs.push(planned_flow(commitment, entry));
+        }
+    }
+
+    Ok(call_zome_method(
+        process,
+        &PROCESS_FLOWS_INDEXING_API_METHOD,
+        IndexFlowsParams { address: process.to_owned(), update: FlowsUpdate::PlannedFlowsChanged { inputs, outputs } },
+    ))
+}
+
+/// Read the Commitments & Intents which are (respectively) inputs and outputs of a Process
+fn read_planned_flows(process: &ProcessAddress) -> RecordAPIResult<(Vec<PlannedFlow>, Vec<PlannedFlow>)> {
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+
+    for (is_input, commitments) in [(true, query_commitments(process, true)?), (false, query_commitments(process, false)?)] {
+        for CommitmentResponse { commitment } in commitments {
+            let flow = PlannedFlow {
+                address: PlannedFlowAddress::Commitment(commitment.id),
+                action: commitment.action,
+                provider: Some(commitment.provider),
+                resource_inventoried_as: commitment.resource_inventoried_as,
+                resource_conforms_to: commitment.resource_conforms_to,
+            };
+            if is_input { inputs.push(flow) } else { outputs.push(flow) }
+        }
+    }
+    for (is_input, intents) in [(true, query_intents(process, true)?), (false, query_intents(process, false)?)] {
+        for IntentResponse { intent } in intents {
+            let flow = PlannedFlow {
+                address: PlannedFlowAddress::Intent(intent.id),
+                action: intent.action,
+                provider: intent.provider,
+                resource_inventoried_as: intent.resource_inventoried_as,
+                resource_conforms_to: intent.resource_conforms_to,
+            };
+            if is_input { inputs.push(flow) } else { outputs.push(flow) }
+        }
+    }
+
+    Ok((inputs, outputs))
+}
+
+fn query_commitments(process: &ProcessAddress, is_input: bool) -> RecordAPIResult<Vec<CommitmentResponse>> {
+    let resp: QueryResults<CommitmentResponse> = call_local_zome_method(
+        read_commitment_index_zome,
+        COMMITMENT_QUERY_API_METHOD.to_string(),
+        QueryInputs { params: CommitmentQueryParams {
+            input_of: if is_input { Some(process.to_owned()) } else { None },
+            output_of: if is_input { None } else { Some(process.to_owned()) },
+            fulfilled_by: None,
+            satisfies: None,
+            clause_of: None,
+            independent_demand_of: None,
+            planned_within: None,
+        } },
+    )?;
+    Ok(resp.results)
+}
+
+fn query_intents(process: &ProcessAddress, is_input: bool) -> RecordAPIResult<Vec<IntentResponse>> {
+    let resp: QueryResults<IntentResponse> = call_local_zome_method(
+        read_intent_index_zome,
+        INTENT_QUERY_API_METHOD.to_string(),
+        QueryInputs { params: IntentQueryParams {
+            input_of: if is_input { Some(process.to_owned()) } else { None },
+            output_of: if is_input { None } else { Some(process.to_owned()) },
+            satisfied_by: None,
+            proposed_in: None,
+        } },
+    )?;
+    Ok(resp.results)
+}
+
+/// Parameters for the Commitment & Intent index zome query APIs
+#[derive(Debug, Serialize)]
+struct QueryInputs<T> {
+    params: T,
+}
+
+/// Results of the Commitment & Intent index zome query APIs
+#[derive(Debug, Deserialize)]
+struct QueryResults<T> {
+    #[serde(default)]
+    results: Vec<T>,
+}
+
+fn planned_flow(address: &CommitmentAddress, entry: &EntryData) -> PlannedFlow {
+    PlannedFlow {
+        address: PlannedFlowAddress::Commitment(address.to_owned()),
+        action: entry.action.to_owned(),
+        provider: Some(entry.provider.to_owned()),
+        resource_inventoried_as: entry.resource_inventoried_as.to_owned(),
+        resource_conforms_to: entry.resource_conforms_to.to_owned(),
+    }
 }
 
 /// Create response from input DHT primitives
@@ -165,6 +377,16 @@ fn read_commitment_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.commitment.index_zome)
 }
 
+/// Properties accessor for zome config
+fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.commitment.plan_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_intent_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.commitment.intent_index_zome
+}
+
 // @see construct_response
 fn get_link_fields(commitment: &CommitmentAddress) -> RecordAPIResult<(
     Vec<FulfillmentAddress>,
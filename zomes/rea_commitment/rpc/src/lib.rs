@@ -13,7 +13,7 @@ use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     RevisionHash,
     ActionId,
-    DateTime, FixedOffset,
+    DateTimeValue,
     ExternalURL,
     LocationAddress,
     AgentAddress,
@@ -58,13 +58,13 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub effort_quantity: Option<QuantityValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_beginning: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_end: Option<DateTime<FixedOffset>>,
+    pub has_end: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
+    pub has_point_in_time: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due: Option<DateTime<FixedOffset>>,
+    pub due: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_location: Option<LocationAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,13 +129,13 @@ pub struct CreateRequest {
     #[serde(default)]
     pub effort_quantity: MaybeUndefined<QuantityValue>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_point_in_time: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_point_in_time: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub due: MaybeUndefined<DateTime<FixedOffset>>,
+    pub due: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub at_location: MaybeUndefined<LocationAddress>,
     #[serde(default)]
@@ -150,6 +150,8 @@ pub struct CreateRequest {
     pub finished: MaybeUndefined<bool>,
     #[serde(default)]
     pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    pub idempotency_key: MaybeUndefined<String>,
 }
 
 impl<'a> CreateRequest {
@@ -187,13 +189,13 @@ pub struct UpdateRequest {
     #[serde(default)]
     pub effort_quantity: MaybeUndefined<QuantityValue>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_point_in_time: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_point_in_time: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub due: MaybeUndefined<DateTime<FixedOffset>>,
+    pub due: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub at_location: MaybeUndefined<LocationAddress>,
     #[serde(default)]
@@ -228,4 +230,6 @@ pub struct QueryParams {
     pub fulfilled_by: Option<FulfillmentAddress>,
     pub satisfies: Option<SatisfactionAddress>,
     pub clause_of: Option<AgreementAddress>,
+    pub independent_demand_of: Option<PlanAddress>,
+    pub planned_within: Option<PlanAddress>,
 }
@@ -18,7 +18,7 @@ use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     RevisionHash,
     ActionId,
-    DateTime, FixedOffset,
+    DateTimeValue,
     ExternalURL,
     CommitmentAddress,
     LocationAddress,
@@ -30,6 +30,7 @@ pub use vf_attributes_hdk::{
     PlanAddress,
 };
 
+use vf_attributes_hdk::validate_date_order;
 use vf_actions::{ validate_flow_action };
 
 use hc_zome_rea_commitment_rpc::{ CreateRequest, UpdateRequest };
@@ -48,6 +49,8 @@ pub struct DnaConfigSlice {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
 pub struct CommitmentZomeConfig {
     pub index_zome: String,
+    pub plan_index_zome: Option<String>,
+    pub intent_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -64,10 +67,10 @@ pub struct EntryData {
     pub resource_conforms_to: Option<ResourceSpecificationAddress>,
     pub resource_quantity: Option<QuantityValue>,
     pub effort_quantity: Option<QuantityValue>,
-    pub has_beginning: Option<DateTime<FixedOffset>>,
-    pub has_end: Option<DateTime<FixedOffset>>,
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
-    pub due: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
+    pub has_end: Option<DateTimeValue>,
+    pub has_point_in_time: Option<DateTimeValue>,
+    pub due: Option<DateTimeValue>,
     pub at_location: Option<LocationAddress>,
     pub agreed_in: Option<ExternalURL>,
     pub clause_of: Option<AgreementAddress>,
@@ -76,11 +79,25 @@ pub struct EntryData {
     pub finished: bool,
     pub in_scope_of: Option<Vec<String>>,
     pub note: Option<String>,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 impl EntryData {
+    /// The `Plan` this commitment is directly part of, where it is not scheduled within a `Process` of that plan
+    pub fn planned_within(&self) -> Option<PlanAddress> {
+        match (&self.input_of, &self.output_of) {
+            (None, None) => self.plan.to_owned(),
+            _ => None,
+        }
+    }
+
     pub fn validate_action(&self) -> Result<(), String> {
-        validate_flow_action(self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned())
+        validate_flow_action("Commitment", self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned())
+    }
+
+    pub fn validate_dates(&self) -> Result<(), String> {
+        validate_date_order(&self.has_beginning, "hasBeginning", &self.has_end, "hasEnd")
     }
 
     pub fn validate_or_fields(&self) -> Result<(), String> {
@@ -127,6 +144,7 @@ impl From<CreateRequest> for EntryData {
             independent_demand_of: e.independent_demand_of.into(),
             finished: e.finished.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_false" binding via Serde
             in_scope_of: e.in_scope_of.into(),
+            idempotency_key: e.idempotency_key.into(),
         }
     }
 }
@@ -158,6 +176,7 @@ impl Updateable<UpdateRequest> for EntryData {
             independent_demand_of: if e.independent_demand_of == MaybeUndefined::Undefined { self.independent_demand_of.clone() } else { e.independent_demand_of.clone().into() },
             finished: if e.finished == MaybeUndefined::Undefined { self.finished.clone() } else { e.finished.clone().to_option().unwrap() },
             in_scope_of: if e.in_scope_of== MaybeUndefined::Undefined { self.in_scope_of.clone() } else { e.in_scope_of.clone().into() },
+            idempotency_key: self.idempotency_key.to_owned(),
             note: if e.note== MaybeUndefined::Undefined { self.note.clone() } else { e.note.clone().into() },
         }
     }
@@ -18,6 +18,9 @@ pub const COMMITMENT_SATISFIES_READ_API_METHOD: &str = "_internal_read_commitmen
 pub const COMMITMENT_INPUT_READ_API_METHOD: &str = "_internal_read_commitment_process_inputs";
 pub const COMMITMENT_INPUT_INDEXING_API_METHOD: &str = "_internal_reindex_process_inputs";
 pub const PROCESS_INPUT_INDEXING_API_METHOD: &str = "index_process_input_commitments";
+pub const PROCESS_FLOWS_INDEXING_API_METHOD: &str = "index_process_flows";
+pub const COMMITMENT_QUERY_API_METHOD: &str = "query_commitments";
+pub const INTENT_QUERY_API_METHOD: &str = "query_intents";
 
 pub const COMMITMENT_OUTPUT_READ_API_METHOD: &str = "_internal_read_commitment_process_outputs";
 pub const COMMITMENT_OUTPUT_INDEXING_API_METHOD: &str = "_internal_reindex_process_outputs";
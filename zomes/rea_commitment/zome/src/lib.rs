@@ -31,6 +31,7 @@ fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResul
         Ok(event_storage) => {
             let record = event_storage.entry();
             record.validate_or_fields()
+                .and_then(|()| { record.validate_dates() })
                 .and_then(|()| { record.validate_action() })
                 .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
                 .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
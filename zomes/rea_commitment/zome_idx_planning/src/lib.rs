@@ -14,4 +14,6 @@ struct Commitment {
     input_of: Local<process, committed_inputs>,
     output_of: Local<process, committed_outputs>,
     clause_of: Local<agreement, commitments>,
+    independent_demand_of: Local<plan, independent_demands>,
+    planned_within: Local<plan, non_process_commitments>,
 }
@@ -8,7 +8,7 @@
  */
 use paste::paste;
 use hdk_records::{
-    RecordAPIResult, OtherCellResult, MaybeUndefined,
+    DataIntegrityError, RecordAPIResult, OtherCellResult, MaybeUndefined,
     local_indexes::{
         query_root_index,
     },
@@ -17,6 +17,8 @@ use hdk_records::{
     },
     records::{
         create_record,
+        read_existing_record,
+        create_idempotency_key_index,
         read_record_entry,
         read_record_entry_by_header,
         update_record,
@@ -36,7 +38,18 @@ use hc_zome_rea_economic_event_rpc::{
     EventResponseCollection as Collection,
     EventResponseEdge as Edge,
 };
-use hc_zome_rea_economic_resource_rpc::{ CreationPayload as ResourceCreationPayload };
+use hc_zome_rea_economic_resource_rpc::{
+    CreationPayload as ResourceCreationPayload,
+    InventoryValidationPayload as ResourceValidationPayload,
+    RecalculateParams as ResourceRecalculateParams,
+    RecalculationResponse as ResourceRecalculationResponse,
+};
+
+use hc_zome_rea_process_rpc::{
+    IndexFlowsParams,
+    FlowsUpdate,
+    ObservedFlow,
+};
 
 use hc_zome_rea_economic_resource_storage::{
     EntryData as EconomicResourceData,
@@ -47,6 +60,11 @@ use hc_zome_rea_economic_resource_lib::{
 };
 
 
+/// Number of steps to follow a chain of triggered events, where not specified by the caller
+const DEFAULT_TRIGGER_CHAIN_DEPTH: usize = 10;
+/// Number of steps to follow resource flows in `trace` & `track`, where not specified by the caller
+const DEFAULT_PROVENANCE_DEPTH: usize = 50;
+
 /// Properties accessor for zome config.
 fn read_economic_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
     conf.economic_event.economic_resource_index_zome
@@ -64,9 +82,40 @@ impl API for EconomicEventZomePermissableDefault {
         entry_def_id: Self::S, process_entry_def_id: Self::S,
         event: EconomicEventCreateRequest, new_inventoried_resource: Option<ResourceCreateRequest>
     ) -> RecordAPIResult<ResponseData> {
+        // if an event has already been recorded with this idempotency key (eg. a retried request), return it without re-applying its effects
+        // :NOTE: this does not guard against concurrent requests with the same key, @see `read_existing_record`
+        let existing_event = read_existing_record::<EntryData, EntryStorage, EconomicEventAddress, _>(
+            &entry_def_id,
+            &event.idempotency_key.to_owned().into(),
+        )?;
+        if let Some((revision_id, event_address, event_entry)) = existing_event {
+            return match new_inventoried_resource {
+                // resources are keyed by the idempotency key of the event which created them, so this resolves to the existing resource
+                Some(economic_resource) => {
+                    let (resource_revision_id, resource_addr, resource_entry) = handle_create_inventory_from_event(&economic_resource, &event)?;
+                    construct_response_with_resource(
+                        &event_address, &revision_id, &event_entry, get_link_fields(&event_address)?,
+                        Some(resource_addr.clone()), &resource_revision_id, resource_entry, get_resource_link_fields(
+                            &entry_def_id, &process_entry_def_id, &resource_addr
+                        )?
+                    )
+                },
+                None => construct_response(&event_address, &revision_id, &event_entry, get_link_fields(&event_address)?),
+            };
+        }
+
         let mut resources_affected: Vec<(RevisionHash, EconomicResourceAddress, EconomicResourceData, EconomicResourceData)> = vec![];
         let mut resource_created: Option<(RevisionHash, EconomicResourceAddress, EconomicResourceData)> = None;
 
+        // if the event is a correction, ensure the event being corrected exists before touching any inventory
+        let corrected_resources: Vec<EconomicResourceAddress> = match event.get_corrects() {
+            Some(corrected_address) => {
+                read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, corrected_address.as_ref())?;
+                read_index!(economic_event(&corrected_address).affects)?
+            },
+            None => vec![],
+        };
+
         // if the event observes a new resource, create that resource & return it in the response
         if let Some(economic_resource) = new_inventoried_resource {
             let new_resource = handle_create_inventory_from_event(
@@ -96,6 +145,16 @@ impl API for EconomicEventZomePermissableDefault {
             create_index!(Local(economic_event.affects(&(resource_data.1)), economic_resource.affected_by(&event_address)))?;
         }
 
+        // Corrections must also be visible in the event logs of any resources affected by the corrected event,
+        // so that the corrected event's effects are netted out when their quantities are rebuilt.
+        for resource_address in corrected_resources.iter() {
+            if resources_affected.iter().any(|r| r.1 == *resource_address) {
+                continue;
+            }
+            create_index!(Local(economic_event.affects(resource_address), economic_resource.affected_by(&event_address)))?;
+            handle_recalculate_resource_inventory(resource_address, true, None)?;
+        }
+
         match resource_created {
             Some((resource_revision_id, resource_addr, resource_entry)) => {
                 construct_response_with_resource(
@@ -112,6 +171,58 @@ impl API for EconomicEventZomePermissableDefault {
         }
     }
 
+    /// Create a set of events in order, such as both sides of an exchange.
+    ///
+    /// This is not an atomic operation. All event records and their combined effects on inventory
+    /// are validated before anything is written, which rejects the batch as a whole in most
+    /// cases. Inventory updates are written by calls to the resource zome which commit independently
+    /// of this one, so if writing an event fails anyway, the events already written are deleted
+    /// (reversing their inventory effects) before the error is returned. Any resources created by
+    /// those events are left in place. If any of those deletions fail, the error returned is an
+    /// `IncompleteRollback` listing the events which remain.
+    ///
+    fn create_economic_events(
+        entry_def_id: Self::S, process_entry_def_id: Self::S,
+        events: Vec<CreateParams>,
+    ) -> RecordAPIResult<Vec<ResponseData>> {
+        for (idx, params) in events.iter().enumerate() {
+            let entry: EntryData = params.event.to_owned().into();
+            entry.validate_or_fields()
+                .and_then(|()| entry.validate_dates())
+                .and_then(|()| entry.validate_action())
+                .map_err(|e| DataIntegrityError::InvalidRecord(format!("event {} in batch: {}", idx, e)))?;
+            if let Some(corrected_address) = params.event.get_corrects() {
+                read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, corrected_address.as_ref())?;
+            }
+        }
+
+        handle_validate_resource_inventory(&events)?;
+
+        let mut created: Vec<ResponseData> = vec![];
+        for (idx, CreateParams { event, new_inventoried_resource }) in events.into_iter().enumerate() {
+            match Self::create_economic_event(entry_def_id, process_entry_def_id, event, new_inventoried_resource) {
+                Ok(response) => created.push(response),
+                Err(e) => {
+                    // compensate for events already written, most recent first
+                    let not_rolled_back: Vec<String> = created.iter().rev()
+                        .filter_map(|written| {
+                            let revision_id = written.economic_event.revision_id.to_owned();
+                            Self::delete_economic_event(revision_id.to_owned())
+                                .err()
+                                .map(|err| format!("{:?} ({})", revision_id, err))
+                        })
+                        .collect();
+                    let e = DataIntegrityError::InvalidRecord(format!("event {} in batch: {}", idx, e));
+                    if not_rolled_back.is_empty() {
+                        return Err(e);
+                    }
+                    return Err(DataIntegrityError::IncompleteRollback(Box::new(e), not_rolled_back.join(", ")));
+                },
+            }
+        }
+        Ok(created)
+    }
+
     fn get_economic_event(entry_def_id: Self::S, address: EconomicEventAddress) -> RecordAPIResult<ResponseData> {
         let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
         construct_response(&base_address, &revision, &entry, get_link_fields(&address)?)
@@ -129,20 +240,49 @@ impl API for EconomicEventZomePermissableDefault {
         // read any referencing indexes
         let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
 
+        // check that reversing this event's effects leaves all affected inventory with a permissible
+        // balance before modifying anything
+        let affected_resources: Vec<EconomicResourceAddress> = read_index!(economic_event(&base_address).affects)?;
+        for resource_address in affected_resources.iter() {
+            handle_recalculate_resource_inventory(resource_address, false, Some(&base_address))?;
+        }
+
         // handle link fields
-        if let Some(process_address) = entry.input_of {
+        if let Some(process_address) = &entry.input_of {
             update_index!(Local(economic_event.input_of.not(&vec![process_address.to_owned()]), process.inputs(&base_address)))?;
+            handle_index_process_flows(process_address, FlowsUpdate::EventRemoved { event: observed_flow(&base_address, &entry), is_input: true })?;
         }
-        if let Some(process_address) = entry.output_of {
+        if let Some(process_address) = &entry.output_of {
             update_index!(Local(economic_event.output_of.not(&vec![process_address.to_owned()]), process.outputs(&base_address)))?;
+            handle_index_process_flows(process_address, FlowsUpdate::EventRemoved { event: observed_flow(&base_address, &entry), is_input: false })?;
         }
         if let Some(agreement_address) = entry.realization_of {
             let _ = update_index!(Remote(economic_event.realization_of.not(&vec![agreement_address.to_owned()]), agreement.economic_events(&base_address)));
         }
+        if let Some(triggering_event) = entry.triggered_by {
+            update_index!(Self(economic_event(&base_address).triggered_by.not(vec![triggering_event].as_slice())))?;
+        }
+        if let Some(corrected_event) = entry.corrects {
+            update_index!(Self(economic_event(&base_address).corrects.not(vec![corrected_event].as_slice())))?;
+        }
+        // corrections of this event no longer have anything to correct
+        for correction in read_index!(economic_event(&base_address).corrected_by)? {
+            update_index!(Self(economic_event(&correction).corrects.not(vec![base_address.to_owned()].as_slice())))?;
+        }
 
         // :TODO: handle cleanup of foreign key fields? (fulfillment, satisfaction)
         // May not be needed due to cross-record deletion validation logic.
 
+        // rebuild affected resources' quantities from the remaining event log in order to reverse the effect
+        // of this event on inventory, then unlink them. The event is excluded explicitly rather than by
+        // unlinking it first, since the resource zome cannot see link removals until this call commits.
+        for resource_address in affected_resources.iter() {
+            handle_recalculate_resource_inventory(resource_address, true, Some(&base_address))?;
+        }
+        if !affected_resources.is_empty() {
+            update_index!(Local(economic_event.affects.not(&affected_resources), economic_resource.affected_by(&base_address)))?;
+        }
+
         // delete entry last as it must be present in order for links to be removed
         delete_record::<EntryStorage, RevisionHash>(&revision_id)
     }
@@ -151,6 +291,63 @@ impl API for EconomicEventZomePermissableDefault {
         let entries_result = query_root_index::<EntryData, EntryStorage, _,_>(&entry_def_id)?;
         handle_list_output(entries_result)
     }
+
+    /// Walk the chain of events triggered by an EconomicEvent (eg. a delivery triggering a payment,
+    /// which triggers a receipt), breadth-first and up to `max_depth` steps from the starting event.
+    ///
+    fn get_trigger_chain(entry_def_id: Self::S, params: TriggerChainParams) -> RecordAPIResult<TriggerChainResponse> {
+        let max_depth = params.max_depth.unwrap_or(DEFAULT_TRIGGER_CHAIN_DEPTH);
+        let mut visited: Vec<EconomicEventAddress> = vec![params.address.to_owned()];
+        let mut frontier: Vec<EconomicEventAddress> = vec![params.address];
+        let mut edges: Vec<TriggerChainEdge> = vec![];
+        let mut truncated = false;
+
+        for depth in 1..=max_depth {
+            let mut next: Vec<EconomicEventAddress> = vec![];
+            for event_address in frontier.iter() {
+                for triggered in read_index!(economic_event(event_address).triggers)? {
+                    // guard against cycles, which may be authored by concurrent edits
+                    if visited.contains(&triggered) {
+                        continue;
+                    }
+                    let (revision_id, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, triggered.as_ref())?;
+                    edges.push(TriggerChainEdge {
+                        node: construct_response(&base_address, &revision_id, &entry, get_link_fields(&base_address)?)?.economic_event,
+                        depth,
+                    });
+                    visited.push(triggered.to_owned());
+                    next.push(triggered);
+                }
+            }
+            frontier = next;
+            if frontier.is_empty() {
+                break;
+            }
+        }
+
+        // check whether the chain continues beyond the depth limit
+        for event_address in frontier.iter() {
+            if !read_index!(economic_event(event_address).triggers)?.is_empty() {
+                truncated = true;
+                break;
+            }
+        }
+
+        Ok(TriggerChainResponse { edges, truncated })
+    }
+
+    /// Walk backwards through the flows which led to a resource, event or process (eg. from a
+    /// finished product to its raw inputs).
+    ///
+    fn trace_provenance(entry_def_id: Self::S, params: ProvenanceParams) -> RecordAPIResult<ProvenanceResponse> {
+        handle_walk_flows(&entry_def_id, params, FlowDirection::Trace)
+    }
+
+    /// Walk forwards through the flows which a resource, event or process went on to take part in.
+    ///
+    fn track_provenance(entry_def_id: Self::S, params: ProvenanceParams) -> RecordAPIResult<ProvenanceResponse> {
+        handle_walk_flows(&entry_def_id, params, FlowDirection::Track)
+    }
 }
 
 // API logic handlers
@@ -178,18 +375,27 @@ fn handle_create_economic_event_record<S>(entry_def_id: S, event: &EconomicEvent
             None => event.to_owned(),
         }
     )?;
+    create_idempotency_key_index(&entry_def_id, &entry_resp.idempotency_key, &base_address)?;
 
     // handle link fields
     // :TODO: propagate errors
     if let EconomicEventCreateRequest { input_of: MaybeUndefined::Some(input_of), .. } = event {
         create_index!(Local(economic_event.input_of(input_of), process.inputs(&base_address)))?;
+        handle_index_process_flows(input_of, FlowsUpdate::EventAdded { event: observed_flow(&base_address, &entry_resp), is_input: true })?;
     };
     if let EconomicEventCreateRequest { output_of: MaybeUndefined::Some(output_of), .. } = event {
         create_index!(Local(economic_event.output_of(output_of), process.outputs(&base_address)))?;
+        handle_index_process_flows(output_of, FlowsUpdate::EventAdded { event: observed_flow(&base_address, &entry_resp), is_input: false })?;
     };
     if let EconomicEventCreateRequest { realization_of: MaybeUndefined::Some(realization_of), .. } = event {
         create_index!(Remote(economic_event.realization_of(realization_of), agreement.realized(&base_address)))?;
     };
+    if let EconomicEventCreateRequest { corrects: MaybeUndefined::Some(corrects), .. } = event {
+        create_index!(Self(economic_event(&base_address).corrects(corrects)))?;
+    };
+    if let EconomicEventCreateRequest { triggered_by: MaybeUndefined::Some(triggered_by), .. } = event {
+        create_index!(Self(economic_event(&base_address).triggered_by(triggered_by)))?;
+    };
 
     Ok((revision_id, base_address, entry_resp))
 }
@@ -235,6 +441,163 @@ fn handle_update_resource_inventory(
     )?)
 }
 
+/// Properties accessor for zome config.
+fn read_process_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.economic_event.process_zome
+}
+
+/// Notify the Process zome that an event has been added to or removed from the inputs or outputs of a Process.
+///
+/// Only the changed event is sent, so that the Process zome can update its indexes without reading any
+/// other flows of the Process- in particular, without depending on the planning DNA being reachable.
+///
+fn handle_index_process_flows(process: &ProcessAddress, update: FlowsUpdate) -> RecordAPIResult<()>
+{
+    Ok(call_local_zome_method(
+        read_process_zome,
+        PROCESS_FLOWS_INDEXING_API_METHOD.to_string(),
+        IndexFlowsParams { address: process.to_owned(), update },
+    )?)
+}
+
+fn observed_flow(address: &EconomicEventAddress, event: &EntryData) -> ObservedFlow {
+    ObservedFlow {
+        address: address.to_owned(),
+        action: event.action.to_owned(),
+        provider: event.provider.to_owned(),
+        resource_inventoried_as: event.resource_inventoried_as.to_owned(),
+        resource_conforms_to: event.resource_conforms_to.to_owned(),
+    }
+}
+
+/// Check the combined effects of a batch of events on existing resources, without writing them
+///
+fn handle_validate_resource_inventory(
+    events: &Vec<CreateParams>,
+) -> RecordAPIResult<()>
+{
+    Ok(call_local_zome_method(
+        read_resource_zome,
+        INVENTORY_VALIDATION_API_METHOD.to_string(),
+        ResourceValidationPayload {
+            events: events.iter()
+                .map(|params| (params.event.to_owned(), params.new_inventoried_resource.to_owned()))
+                .collect(),
+        },
+    )?)
+}
+
+/// Handle reversal of an event's effects on existing resources, by recalculating
+/// quantities from their remaining event logs
+///
+fn handle_recalculate_resource_inventory(
+    resource_address: &EconomicResourceAddress,
+    write_correction: bool,
+    excluding_event: Option<&EconomicEventAddress>,
+) -> RecordAPIResult<ResourceRecalculationResponse>
+{
+    Ok(call_local_zome_method(
+        read_resource_zome,
+        INVENTORY_RECALCULATE_API_METHOD.to_string(),
+        ResourceRecalculateParams {
+            address: resource_address.to_owned(),
+            write_correction,
+            excluding_event: excluding_event.cloned(),
+        },
+    )?)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FlowDirection {
+    Trace,
+    Track,
+}
+
+/// Breadth-first traversal of resource flows, in the manner of the ValueFlows `trace` & `track` algorithms.
+/// Each record is visited at most once, so cyclic flows (eg. resources returned to their origin) terminate.
+///
+fn handle_walk_flows<S>(entry_def_id: S, params: ProvenanceParams, direction: FlowDirection) -> RecordAPIResult<ProvenanceResponse>
+    where S: AsRef<str>
+{
+    let max_depth = params.max_depth.unwrap_or(DEFAULT_PROVENANCE_DEPTH);
+    let mut visited: Vec<ProductionFlowItem> = vec![params.start.to_owned()];
+    let mut frontier: Vec<ProductionFlowItem> = vec![params.start];
+    let mut edges: Vec<ProvenanceEdge> = vec![];
+    let mut truncated = false;
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        let mut next: Vec<ProductionFlowItem> = vec![];
+        for item in frontier.iter() {
+            for neighbour in get_flow_neighbours(&entry_def_id, item, direction)? {
+                if visited.contains(&neighbour) {
+                    continue;
+                }
+                if depth == max_depth {
+                    truncated = true;
+                    continue;
+                }
+                visited.push(neighbour.to_owned());
+                edges.push(ProvenanceEdge { node: neighbour.to_owned(), depth: depth + 1 });
+                next.push(neighbour);
+            }
+        }
+        depth += 1;
+        frontier = next;
+    }
+
+    Ok(ProvenanceResponse { edges, truncated })
+}
+
+/// Determine the records one step before (`Trace`) or after (`Track`) some record in a flow of resources
+///
+fn get_flow_neighbours<S>(entry_def_id: S, item: &ProductionFlowItem, direction: FlowDirection) -> RecordAPIResult<Vec<ProductionFlowItem>>
+    where S: AsRef<str>
+{
+    Ok(match item {
+        ProductionFlowItem::Process(process_address) => {
+            let events: Vec<EconomicEventAddress> = match direction {
+                FlowDirection::Trace => read_index!(process(process_address).inputs)?,
+                FlowDirection::Track => read_index!(process(process_address).outputs)?,
+            };
+            events.into_iter().map(ProductionFlowItem::EconomicEvent).collect()
+        },
+        ProductionFlowItem::EconomicResource(resource_address) => {
+            let mut flows: Vec<ProductionFlowItem> = vec![];
+            let affecting: Vec<EconomicEventAddress> = read_index!(economic_resource(resource_address).affected_by)?;
+            for event_address in affecting {
+                let (_, _, event) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, event_address.as_ref())?;
+                let is_flow = match direction {
+                    FlowDirection::Trace => event.is_incoming_to(resource_address),
+                    FlowDirection::Track => event.is_outgoing_from(resource_address),
+                };
+                if is_flow {
+                    flows.push(ProductionFlowItem::EconomicEvent(event_address));
+                }
+            }
+            flows
+        },
+        ProductionFlowItem::EconomicEvent(event_address) => {
+            let (_, _, event) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, event_address.as_ref())?;
+            match direction {
+                // outputs come from their process; inputs & transfers draw from the providing resource
+                FlowDirection::Trace => match (&event.output_of, &event.resource_inventoried_as) {
+                    (Some(process_address), _) => vec![ProductionFlowItem::Process(process_address.to_owned())],
+                    (None, Some(resource)) if !event.is_incoming_to(resource) => vec![ProductionFlowItem::EconomicResource(resource.to_owned())],
+                    _ => vec![],
+                },
+                // inputs go on to their process; transfers & outputs result in the receiving resource
+                FlowDirection::Track => match (&event.input_of, &event.to_resource_inventoried_as, &event.resource_inventoried_as) {
+                    (Some(process_address), _, _) => vec![ProductionFlowItem::Process(process_address.to_owned())],
+                    (None, Some(to_resource), _) => vec![ProductionFlowItem::EconomicResource(to_resource.to_owned())],
+                    (None, None, Some(resource)) if event.is_incoming_to(resource) => vec![ProductionFlowItem::EconomicResource(resource.to_owned())],
+                    _ => vec![],
+                },
+            }
+        },
+    })
+}
+
 fn handle_list_output(entries_result: Vec<RecordAPIResult<(RevisionHash, EconomicEventAddress, EntryData)>>) -> RecordAPIResult<Collection> {
     let edges = entries_result.iter()
         .cloned()
@@ -275,9 +638,19 @@ pub fn construct_response_with_resource<'a>(
     event: &EntryData, (
         fulfillments,
         satisfactions,
+        corrected_by,
+        triggers,
+        settlements,
+        appreciated_by,
+        appreciation_of,
     ): (
         Vec<FulfillmentAddress>,
         Vec<SatisfactionAddress>,
+        Option<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<SettlementAddress>,
+        Vec<AppreciationAddress>,
+        Vec<AppreciationAddress>,
     ),
     resource_address: Option<EconomicResourceAddress>,
     resource_revision_id: &RevisionHash,
@@ -286,11 +659,15 @@ pub fn construct_response_with_resource<'a>(
         stage,
         state,
         contains,
+        trace,
+        track,
      ): (
         Option<EconomicResourceAddress>,
         Option<ProcessSpecificationAddress>,
         Option<ActionId>,
         Vec<EconomicResourceAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
     ),
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
@@ -315,13 +692,19 @@ pub fn construct_response_with_resource<'a>(
             at_location: event.at_location.to_owned(),
             agreed_in: event.agreed_in.to_owned(),
             triggered_by: event.triggered_by.to_owned(),
+            corrects: event.corrects.to_owned(),
             realization_of: event.realization_of.to_owned(),
             in_scope_of: event.in_scope_of.to_owned(),
             fulfills: fulfillments.to_owned(),
             satisfies: satisfactions.to_owned(),
+            corrected_by: corrected_by.to_owned(),
+            triggers: triggers.to_owned(),
+            settles: settlements.to_owned(),
+            appreciated_by: appreciated_by.to_owned(),
+            appreciation_of: appreciation_of.to_owned(),
         },
         economic_resource: match resource_address {
-            Some(addr) => Some(construct_resource_response(&addr, &resource_revision_id, &resource, (contained_in, stage, state, contains))?),
+            Some(addr) => Some(construct_resource_response(&addr, &resource_revision_id, &resource, (contained_in, stage, state, contains, trace, track))?),
             None => None,
         },
     })
@@ -332,9 +715,19 @@ pub fn construct_response<'a>(
     address: &EconomicEventAddress, revision_id: &RevisionHash, e: &EntryData, (
         fulfillments,
         satisfactions,
+        corrected_by,
+        triggers,
+        settlements,
+        appreciated_by,
+        appreciation_of,
     ): (
         Vec<FulfillmentAddress>,
         Vec<SatisfactionAddress>,
+        Option<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<SettlementAddress>,
+        Vec<AppreciationAddress>,
+        Vec<AppreciationAddress>,
     )
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
@@ -359,10 +752,16 @@ pub fn construct_response<'a>(
             at_location: e.at_location.to_owned(),
             agreed_in: e.agreed_in.to_owned(),
             triggered_by: e.triggered_by.to_owned(),
+            corrects: e.corrects.to_owned(),
             realization_of: e.realization_of.to_owned(),
             in_scope_of: e.in_scope_of.to_owned(),
             fulfills: fulfillments.to_owned(),
             satisfies: satisfactions.to_owned(),
+            corrected_by: corrected_by.to_owned(),
+            triggers: triggers.to_owned(),
+            settles: settlements.to_owned(),
+            appreciated_by: appreciated_by.to_owned(),
+            appreciation_of: appreciation_of.to_owned(),
         },
         economic_resource: None,
     })
@@ -372,14 +771,24 @@ pub fn construct_list_response<'a>(
     address: &EconomicEventAddress, revision_id: &RevisionHash, e: &EntryData, (
         fulfillments,
         satisfactions,
+        corrected_by,
+        triggers,
+        settlements,
+        appreciated_by,
+        appreciation_of,
     ): (
         Vec<FulfillmentAddress>,
         Vec<SatisfactionAddress>,
+        Option<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<SettlementAddress>,
+        Vec<AppreciationAddress>,
+        Vec<AppreciationAddress>,
     )
 ) -> RecordAPIResult<Edge> {
     let record_cursor: Vec<u8> = address.to_owned().into();
     Ok(Edge {
-        node: construct_response(address, revision_id, e, (fulfillments, satisfactions))?.economic_event,
+        node: construct_response(address, revision_id, e, (fulfillments, satisfactions, corrected_by, triggers, settlements, appreciated_by, appreciation_of))?.economic_event,
         // :TODO: use HoloHashb64 once API stabilises
         cursor: String::from_utf8(record_cursor).unwrap_or("".to_string())
     })
@@ -389,10 +798,20 @@ pub fn construct_list_response<'a>(
 pub fn get_link_fields(event: &EconomicEventAddress) -> RecordAPIResult<(
     Vec<FulfillmentAddress>,
     Vec<SatisfactionAddress>,
+    Option<EconomicEventAddress>,
+    Vec<EconomicEventAddress>,
+    Vec<SettlementAddress>,
+    Vec<AppreciationAddress>,
+    Vec<AppreciationAddress>,
 )> {
     Ok((
         read_index!(economic_event(event).fulfills)?,
         read_index!(economic_event(event).satisfies)?,
+        read_index!(economic_event(event).corrected_by)?.pop(),
+        read_index!(economic_event(event).triggers)?,
+        read_index!(economic_event(event).settles)?,
+        read_index!(economic_event(event).appreciated_by)?,
+        read_index!(economic_event(event).appreciation_of)?,
     ))
 }
 
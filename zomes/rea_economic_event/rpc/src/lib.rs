@@ -26,10 +26,12 @@ pub use vf_attributes_hdk::{
     CommitmentAddress,
     FulfillmentAddress,
     SatisfactionAddress,
+    SettlementAddress,
+    AppreciationAddress,
     AgreementAddress,
     ProductBatchAddress,
     UnitId,
-    DateTime, FixedOffset,
+    DateTimeValue,
 };
 
 //---------------- EXTERNAL RECORD STRUCTURE ----------------
@@ -62,11 +64,11 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub effort_quantity: Option<QuantityValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_beginning: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_end: Option<DateTime<FixedOffset>>,
+    pub has_end: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
+    pub has_point_in_time: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_location: Option<LocationAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,6 +78,8 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub triggered_by: Option<EconomicEventAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub corrects: Option<EconomicEventAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub in_scope_of: Option<Vec<String>>,
 
     // LINK FIELDS
@@ -85,6 +89,21 @@ pub struct Response {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub satisfies: Vec<SatisfactionAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub corrected_by: Option<EconomicEventAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub triggers: Vec<EconomicEventAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub settles: Vec<SettlementAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub appreciated_by: Vec<AppreciationAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub appreciation_of: Vec<AppreciationAddress>,
 }
 
 /// I/O struct to describe EconomicResources, including all managed link fields
@@ -126,10 +145,12 @@ pub struct ResourceResponse {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub contains: Vec<EconomicResourceAddress>,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // trace: Option<Vec<EconomicEventAddress>>,
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // track: Option<Vec<EconomicEventAddress>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trace: Vec<EconomicEventAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub track: Vec<EconomicEventAddress>,
 }
 
 /// I/O struct to describe what is returned outside the gateway
@@ -216,11 +237,11 @@ pub struct CreateRequest {
     #[serde(default)]
     pub effort_quantity: MaybeUndefined<QuantityValue>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_point_in_time: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_point_in_time: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub at_location: MaybeUndefined<LocationAddress>,
     #[serde(default)]
@@ -230,7 +251,11 @@ pub struct CreateRequest {
     #[serde(default)]
     pub triggered_by: MaybeUndefined<EconomicEventAddress>,
     #[serde(default)]
+    pub corrects: MaybeUndefined<EconomicEventAddress>,
+    #[serde(default)]
     pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    pub idempotency_key: MaybeUndefined<String>,
 
     // :SHONK: internal field used in updating linked resource quantities
     #[serde(default)]
@@ -265,6 +290,10 @@ impl<'a> CreateRequest {
     pub fn get_realization_of(&'a self) -> MaybeUndefined<AgreementAddress> {
         self.realization_of.to_owned()
     }
+
+    pub fn get_corrects(&'a self) -> Option<EconomicEventAddress> {
+        self.corrects.to_owned().to_option()
+    }
 }
 
 // used in EconomicResource API
@@ -299,6 +328,12 @@ pub struct CreateParams {
     pub new_inventoried_resource: Option<ResourceCreateRequest>,
 }
 
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateParams {
+    pub events: Vec<CreateParams>,
+}
+
 //---------------- UPDATE REQUEST ----------------
 
 /// I/O struct to describe the complete input record, including all managed links
@@ -342,4 +377,73 @@ pub struct QueryParams {
     pub fulfills: Option<CommitmentAddress>,
     pub realization_of: Option<AgreementAddress>,
     pub affects: Option<EconomicResourceAddress>,
+    pub corrects: Option<EconomicEventAddress>,
+    pub corrected_by: Option<EconomicEventAddress>,
+    pub triggered_by: Option<EconomicEventAddress>,
+    pub triggers: Option<EconomicEventAddress>,
+    pub settles: Option<SettlementAddress>,
+    pub appreciated_by: Option<AppreciationAddress>,
+    pub appreciation_of: Option<AppreciationAddress>,
+}
+
+//---------------- PROVENANCE REQUEST ----------------
+
+/// A record participating in a flow of resources through processes
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug, PartialEq)]
+pub enum ProductionFlowItem {
+    EconomicResource(EconomicResourceAddress),
+    EconomicEvent(EconomicEventAddress),
+    Process(ProcessAddress),
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceParams {
+    pub start: ProductionFlowItem,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+}
+
+/// A record reached by a provenance traversal, with its distance from the starting record
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceEdge {
+    pub node: ProductionFlowItem,
+    pub depth: usize,
+}
+
+/// I/O struct to describe the result of a `trace` or `track` traversal, in breadth-first order.
+/// `truncated` is set if further records exist beyond the depth limit.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceResponse {
+    pub edges: Vec<ProvenanceEdge>,
+    pub truncated: bool,
+}
+
+//---------------- TRIGGER CHAIN REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerChainParams {
+    pub address: EconomicEventAddress,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+}
+
+/// An event in a trigger chain, with its distance from the starting event
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerChainEdge {
+    pub node: Response,
+    pub depth: usize,
+}
+
+/// I/O struct to describe the events triggered (directly or indirectly) by an EconomicEvent,
+/// in breadth-first order. `truncated` is set if the chain continues beyond the depth limit.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerChainResponse {
+    pub edges: Vec<TriggerChainEdge>,
+    pub truncated: bool,
 }
@@ -25,9 +25,10 @@ use vf_attributes_hdk::{
     ProcessAddress,
     ResourceSpecificationAddress,
     AgreementAddress,
-    DateTime, FixedOffset,
+    DateTimeValue,
+    validate_date_order,
 };
-use vf_actions::{ validate_flow_action, validate_move_inventories };
+use vf_actions::{ ActionEffect, ContainedEffect, get_builtin_action, validate_flow_action, validate_move_inventories, validate_container_inventories };
 use hc_zome_rea_economic_event_rpc::*;
 
 // :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
@@ -48,6 +49,8 @@ pub struct EconomicEventZomeConfig {
     pub economic_resource_zome: Option<String>,
     pub economic_resource_index_zome: Option<String>,
     pub process_index_zome: Option<String>,
+    // zome ID of a ValueFlows `Process`-compatible zome, notified when input & output events change so that the Process can be reindexed.
+    pub process_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -65,23 +68,32 @@ pub struct EntryData {
     pub resource_conforms_to: Option<ResourceSpecificationAddress>,
     pub resource_quantity: Option<QuantityValue>,
     pub effort_quantity: Option<QuantityValue>,
-    pub has_beginning: Option<DateTime<FixedOffset>>,
-    pub has_end: Option<DateTime<FixedOffset>>,
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
+    pub has_end: Option<DateTimeValue>,
+    pub has_point_in_time: Option<DateTimeValue>,
     pub at_location: Option<LocationAddress>,
     pub agreed_in: Option<ExternalURL>,
     pub realization_of: Option<AgreementAddress>,
     pub triggered_by: Option<EconomicEventAddress>,
+    #[serde(default)]
+    pub corrects: Option<EconomicEventAddress>,
     pub in_scope_of: Option<Vec<String>>,
     pub note: Option<String>,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 impl EntryData {
     pub fn validate_action(&self) -> Result<(), String> {
-        let result = validate_flow_action(self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned());
+        let result = validate_flow_action("EconomicEvent", self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned());
         if result.is_ok() && self.action.as_ref() == "move" {
             return validate_move_inventories(self.resource_inventoried_as.to_owned(), self.to_resource_inventoried_as.to_owned());
         }
+        if let (Ok(_), Some(action)) = (&result, get_builtin_action(self.action.as_ref())) {
+            if action.contained_effect == ContainedEffect::Update {
+                return validate_container_inventories(self.action.as_ref(), self.resource_inventoried_as.to_owned(), self.to_resource_inventoried_as.to_owned());
+            }
+        }
         return result;
     }
 
@@ -97,6 +109,40 @@ impl EntryData {
         }
         Ok(())
     }
+
+    pub fn validate_dates(&self) -> Result<(), String> {
+        if self.has_point_in_time.is_some() && (self.has_beginning.is_some() || self.has_end.is_some()) {
+            return Err("EconomicEvent must have either an exact time or a beginning and/or end, not both".into());
+        }
+        validate_date_order(&self.has_beginning, "hasBeginning", &self.has_end, "hasEnd")
+    }
+
+    /// Whether this event brings the given resource into being or adds to it,
+    /// ie. whether the event precedes the resource when tracing provenance.
+    pub fn is_incoming_to(&self, resource: &EconomicResourceAddress) -> bool {
+        if self.to_resource_inventoried_as.as_ref() == Some(resource) {
+            return true;
+        }
+        if self.resource_inventoried_as.as_ref() != Some(resource) {
+            return false;
+        }
+        if self.output_of.is_some() {
+            return true;
+        }
+        if self.input_of.is_some() || self.to_resource_inventoried_as.is_some() {
+            return false;
+        }
+        match get_builtin_action(self.action.as_ref()) {
+            Some(action) => action.resource_effect == ActionEffect::Increment,
+            None => false,
+        }
+    }
+
+    /// Whether this event draws upon the given resource, ie. whether the event
+    /// follows the resource when tracking its use.
+    pub fn is_outgoing_from(&self, resource: &EconomicResourceAddress) -> bool {
+        self.resource_inventoried_as.as_ref() == Some(resource) && !self.is_incoming_to(resource)
+    }
 }
 
 generate_record_entry!(EntryData, EconomicEventAddress, EntryStorage);
@@ -127,8 +173,10 @@ impl From<CreateRequest> for EntryData {
             agreed_in: e.agreed_in.into(),
             realization_of: e.realization_of.into(),
             triggered_by: e.triggered_by.into(),
+            corrects: e.corrects.into(),
             at_location: e.at_location.into(),
             in_scope_of: e.in_scope_of.into(),
+            idempotency_key: e.idempotency_key.into(),
         }
     }
 }
@@ -155,9 +203,11 @@ impl Updateable<UpdateRequest> for EntryData {
             has_point_in_time: self.has_point_in_time.to_owned(),
             agreed_in: self.agreed_in.to_owned(),
             triggered_by: self.triggered_by.to_owned(),
+            corrects: self.corrects.to_owned(),
             realization_of: self.realization_of.to_owned(),
             at_location: self.at_location.to_owned(),
             in_scope_of: if e.in_scope_of== MaybeUndefined::Undefined { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
+            idempotency_key: self.idempotency_key.to_owned(),
             note: if e.note== MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
         }
     }
@@ -9,6 +9,9 @@ pub const EVENT_ENTRY_TYPE: &str = "vf_economic_event";
 
 pub const EVENT_FULFILLS_LINK_TAG: &str = "fulfills";
 pub const EVENT_SATISFIES_LINK_TAG: &str = "satisfies";
+pub const EVENT_SETTLES_LINK_TAG: &str = "settles";
+pub const EVENT_APPRECIATED_BY_LINK_TAG: &str = "appreciated_by";
+pub const EVENT_APPRECIATION_OF_LINK_TAG: &str = "appreciation_of";
 pub const EVENT_INPUT_OF_LINK_TAG: &str = "input_of";
 pub const EVENT_OUTPUT_OF_LINK_TAG: &str = "output_of";
 pub const EVENT_REALIZATION_OF_LINK_TAG: &str = "realization_of";
@@ -16,3 +19,6 @@ pub const EVENT_AFFECTS_RESOURCE_LINK_TAG: &str = "affects";
 
 pub const INVENTORY_CREATION_API_METHOD: &str = "_internal_create_inventory";
 pub const INVENTORY_UPDATE_API_METHOD: &str = "_internal_update_inventory";
+pub const INVENTORY_VALIDATION_API_METHOD: &str = "_internal_validate_inventory";
+pub const INVENTORY_RECALCULATE_API_METHOD: &str = "recalculate_economic_resource";
+pub const PROCESS_FLOWS_INDEXING_API_METHOD: &str = "index_process_flows";
@@ -8,10 +8,16 @@ pub trait API {
     fn create_economic_event(entry_def_id: Self::S, process_entry_def_id: Self::S,
         event: CreateRequest, new_inventoried_resource: Option<ResourceCreateRequest>
     ) -> RecordAPIResult<ResponseData>;
+    fn create_economic_events(entry_def_id: Self::S, process_entry_def_id: Self::S,
+        events: Vec<CreateParams>,
+    ) -> RecordAPIResult<Vec<ResponseData>>;
     fn get_economic_event(entry_def_id: Self::S, address: EconomicEventAddress) -> RecordAPIResult<ResponseData>;
     fn update_economic_event(entry_def_id: Self::S, event: UpdateRequest) -> RecordAPIResult<ResponseData>;
     fn delete_economic_event(revision_id: RevisionHash) -> RecordAPIResult<bool>;
     fn get_all_economic_events(entry_def_id: Self::S) -> RecordAPIResult<EventResponseCollection>;
+    fn get_trigger_chain(entry_def_id: Self::S, params: TriggerChainParams) -> RecordAPIResult<TriggerChainResponse>;
+    fn trace_provenance(entry_def_id: Self::S, params: ProvenanceParams) -> RecordAPIResult<ProvenanceResponse>;
+    fn track_provenance(entry_def_id: Self::S, params: ProvenanceParams) -> RecordAPIResult<ProvenanceResponse>;
 }
 
 /// Macro to programatically and predictably bind an `API` implementation to a
@@ -31,6 +37,14 @@ macro_rules! declare_economic_event_zome_api {
             )?)
         }
 
+        #[hdk_extern]
+        fn create_economic_events(BatchCreateParams { events }: BatchCreateParams) -> ExternResult<Vec<ResponseData>> {
+            Ok(<$zome_api>::create_economic_events(
+                EVENT_ENTRY_TYPE, PROCESS_ENTRY_TYPE,
+                events,
+            )?)
+        }
+
         #[hdk_extern]
         fn get_economic_event(ByAddress { address }: ByAddress<EconomicEventAddress>) -> ExternResult<ResponseData> {
             Ok(<$zome_api>::get_economic_event(EVENT_ENTRY_TYPE, address)?)
@@ -50,6 +64,21 @@ macro_rules! declare_economic_event_zome_api {
         fn get_all_economic_events(_: ()) -> ExternResult<EventResponseCollection> {
             Ok(<$zome_api>::get_all_economic_events(EVENT_ENTRY_TYPE)?)
         }
+
+        #[hdk_extern]
+        fn get_trigger_chain(params: TriggerChainParams) -> ExternResult<TriggerChainResponse> {
+            Ok(<$zome_api>::get_trigger_chain(EVENT_ENTRY_TYPE, params)?)
+        }
+
+        #[hdk_extern]
+        fn trace_provenance(params: ProvenanceParams) -> ExternResult<ProvenanceResponse> {
+            Ok(<$zome_api>::trace_provenance(EVENT_ENTRY_TYPE, params)?)
+        }
+
+        #[hdk_extern]
+        fn track_provenance(params: ProvenanceParams) -> ExternResult<ProvenanceResponse> {
+            Ok(<$zome_api>::track_provenance(EVENT_ENTRY_TYPE, params)?)
+        }
     };
 }
 
@@ -79,6 +108,7 @@ macro_rules! declare_economic_event_zome_validation_defaults {
                 Ok(event_storage) => {
                     let record = event_storage.entry();
                     record.validate_or_fields()
+                        .and_then(|()| { record.validate_dates() })
                         .and_then(|()| { record.validate_action() })
                         .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
                         .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
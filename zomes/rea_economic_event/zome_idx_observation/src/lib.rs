@@ -14,6 +14,13 @@ struct EconomicEvent {
     realization_of: Remote<agreement, realized_by>,
     satisfies: Remote<satisfaction, satisfied_by>,
     fulfills: Remote<fulfillment, fulfilled_by>,
+    settles: Local<settlement, settled_by>,
+    appreciated_by: Local<appreciation, appreciation_of>,
+    appreciation_of: Local<appreciation, appreciation_with>,
+    corrects: Local<economic_event, corrected_by>,
+    corrected_by: Local<economic_event, corrects>,
+    triggered_by: Local<economic_event, triggers>,
+    triggers: Local<economic_event, triggered_by>,
 
     // internal indexes (not part of REA spec)
     affects: Local<economic_resource, affected_by>,
@@ -7,15 +7,20 @@
  * @package Holo-REA
  */
 use paste::paste;
+use hdk::prelude::TryFrom;
 use hdk_records::{
     DataIntegrityError, RecordAPIResult, MaybeUndefined,
+    record_interface::Updateable,
     local_indexes::{
         query_root_index,
     },
     records::{
         get_latest_header_hash,
         create_record,
+        read_existing_record,
+        create_idempotency_key_index,
         read_record_entry,
+        read_record_entry_by_header,
         update_record,
     },
     EntryHash,
@@ -29,6 +34,7 @@ use vf_attributes_hdk::{
     ActionId,
     ProcessSpecificationAddress,
 };
+use vf_actions::{ StageEffect, StateEffect, get_builtin_action };
 
 pub use hc_zome_rea_economic_resource_storage_consts::*;
 pub use hc_zome_rea_economic_event_storage_consts::{EVENT_ENTRY_TYPE};
@@ -72,18 +78,23 @@ impl API for EconomicResourceZomePermissableDefault {
     ///
     fn create_inventory_from_event(resource_entry_def_id: Self::S, params: CreationPayload) -> RecordAPIResult<(RevisionHash, EconomicResourceAddress, EntryData)>
     {
-        // :TODO: move this assertion to validation callback
-        if let MaybeUndefined::Some(_sent_inventory_id) = &params.get_event_params().resource_inventoried_as {
-            return Err(DataIntegrityError::RemoteRequestError("cannot create a new EconomicResource and specify an inventoried resource ID in the same event".to_string()));
-        }
-
         let resource_params = params.get_resource_params().clone();
         let resource_spec = params.get_resource_specification_id();
+        // resources are keyed by the idempotency key of the event which created them
+        let idempotency_key: Option<String> = params.get_event_params().idempotency_key.to_owned().into();
+
+        // resource already created by a retried request
+        if let Some(existing) = read_existing_record::<EntryData, EntryStorage, EconomicResourceAddress, _>(&resource_entry_def_id, &idempotency_key)? {
+            return Ok(existing);
+        }
+
+        let new_entry = prepare_inventory_creation(params)?;
 
         let (revision_id, base_address, entry_resp): (_, EconomicResourceAddress, EntryData) = create_record(
             &resource_entry_def_id,
-            params.with_inventory_type(ResourceInventoryType::ProvidingInventory),  // inventories can only be inited by their owners initially
+            new_entry,
         )?;
+        create_idempotency_key_index(&resource_entry_def_id, &idempotency_key, &base_address)?;
 
         // :NOTE: this will always run- resource without a specification ID would fail entry validation (implicit in the above)
         if let Some(conforms_to) = resource_spec {
@@ -99,11 +110,17 @@ impl API for EconomicResourceZomePermissableDefault {
     fn get_economic_resource(entry_def_id: Self::S, event_entry_def_id: Self::S, process_entry_def_id: Self::S, address: EconomicResourceAddress) -> RecordAPIResult<ResponseData>
     {
         let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
-        construct_response(&base_address, &revision, &entry, get_link_fields(&event_entry_def_id, &process_entry_def_id, &address)?)
+        let events = read_affecting_events(&event_entry_def_id, &base_address)?;
+        let entry = with_derived_quantities(&base_address, entry, events.as_slice(), None)?;
+        construct_response(&base_address, &revision, &entry, get_link_fields_from_events(&process_entry_def_id, &base_address, events.as_slice())?)
     }
 
     /// Handle update of resources by iterative reduction of event records over time.
     ///
+    /// Quantities are derived from the full set of events affecting each resource (see
+    /// `with_derived_quantities`), so the choice of revision to update from does not
+    /// affect the resulting balance when events are authored concurrently.
+    ///
     fn update_inventory_from_event(
         resource_entry_def_id: Self::S,
         event: EventCreateRequest,
@@ -116,18 +133,21 @@ impl API for EconomicResourceZomePermissableDefault {
             let inv_entry_hash: &EntryHash = receiver_inventory.as_ref();
             resources_affected.push(handle_update_inventory_resource(
                 &resource_entry_def_id,
-                &get_latest_header_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Should error on mismatch and return latest valid ID
+                &get_latest_header_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Quantities are derived from the event log, but other fields remain last-writer-wins
                 event.with_inventory_type(ResourceInventoryType::ReceivingInventory),
             )?);
         }
         // after receiver, run provider. This entry data will be returned in the response.
         if let MaybeUndefined::Some(provider_inventory) = &event.resource_inventoried_as {
             let inv_entry_hash: &EntryHash = provider_inventory.as_ref();
-            resources_affected.push(handle_update_inventory_resource(
+            let updated = handle_update_inventory_resource(
                 &resource_entry_def_id,
-                &get_latest_header_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Should error on mismatch and return latest valid ID
+                &get_latest_header_hash(inv_entry_hash.clone())?,   // :TODO: temporal reduction here! Quantities are derived from the event log, but other fields remain last-writer-wins
                 event.with_inventory_type(ResourceInventoryType::ProvidingInventory),
-            )?);
+            )?;
+            // 'combine' & 'separate' events alter resource containment
+            update_containment_index(&updated.1, &updated.2, &updated.3)?;
+            resources_affected.push(updated);
         }
 
         Ok(resources_affected)
@@ -139,12 +159,12 @@ impl API for EconomicResourceZomePermissableDefault {
         let (revision_id, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &address, resource)?;
 
         // :TODO: this may eventually be moved to an EconomicEvent update, see https://lab.allmende.io/valueflows/valueflows/-/issues/637
-        let now_contained = if let Some(contained) = &entry.contained_in { vec![contained.clone()] } else { vec![] };
-        let prev_contained = if let Some(contained) = &prev_entry.contained_in { vec![contained.clone()] } else { vec![] };
-        update_index!(Self(economic_resource(&identity_address).contained_in(now_contained.as_slice()).not(prev_contained.as_slice())))?;
+        update_containment_index(&identity_address, &entry, &prev_entry)?;
+        let events = read_affecting_events(&event_entry_def_id, &identity_address)?;
+        let entry = with_derived_quantities(&identity_address, entry, events.as_slice(), None)?;
 
         // :TODO: optimise this- should pass results from `replace_direct_index` instead of retrieving from `get_link_fields` where updates
-        construct_response(&identity_address, &revision_id, &entry, get_link_fields(&event_entry_def_id, &process_entry_def_id, &identity_address)?)
+        construct_response(&identity_address, &revision_id, &entry, get_link_fields_from_events(&process_entry_def_id, &identity_address, events.as_slice())?)
     }
 
     fn get_all_economic_resources(entry_def_id: Self::S, event_entry_def_id: Self::S, process_entry_def_id: Self::S) -> RecordAPIResult<Collection>
@@ -153,6 +173,70 @@ impl API for EconomicResourceZomePermissableDefault {
 
         handle_list_output(event_entry_def_id, process_entry_def_id, entries_result)
     }
+
+    /// Rebuild resource quantities by replaying all affecting events, reporting any
+    /// difference against the stored record.
+    ///
+    fn recalculate_economic_resource(entry_def_id: Self::S, event_entry_def_id: Self::S, params: RecalculateParams) -> RecordAPIResult<RecalculationResponse>
+    {
+        let (revision_id, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, params.address.as_ref())?;
+
+        let events = read_affecting_events(&event_entry_def_id, &base_address)?;
+        let replayed = replay_inventory_events(&base_address, &entry, events.as_slice(), params.excluding_event.as_ref())?;
+        if params.excluding_event.is_some() {
+            validate_inventory_balance(Some(&base_address), Some(&entry), &entry.update_with(replayed.to_owned()))?;
+        }
+
+        let mut discrepancies: Vec<String> = vec![];
+        if replayed.accounting_quantity != entry.accounting_quantity {
+            discrepancies.push(format!("accounting quantity stored as {:?}, event log gives {:?}", entry.accounting_quantity, replayed.accounting_quantity));
+        }
+        if replayed.onhand_quantity != entry.onhand_quantity {
+            discrepancies.push(format!("onhand quantity stored as {:?}, event log gives {:?}", entry.onhand_quantity, replayed.onhand_quantity));
+        }
+
+        let corrected = params.write_correction && !discrepancies.is_empty();
+        let revision_id = if corrected {
+            let (new_revision_id, _, _, _): (_, EconomicResourceAddress, EntryData, EntryData) = update_record(&entry_def_id, &revision_id, replayed.to_owned())?;
+            new_revision_id
+        } else {
+            revision_id
+        };
+
+        Ok(RecalculationResponse {
+            id: base_address,
+            revision_id,
+            accounting_quantity: replayed.accounting_quantity,
+            onhand_quantity: replayed.onhand_quantity,
+            discrepancies,
+            corrected,
+        })
+    }
+
+    /// Check that a batch of events can be applied to inventory in sequence, without writing anything.
+    /// Each event sees the simulated effects of all events before it in the batch.
+    ///
+    /// :WARNING: Should only ever be wired up as the dependency of an EconomicEvent zome.
+    ///
+    fn validate_inventory_effects(resource_entry_def_id: Self::S, event_entry_def_id: Self::S, params: InventoryValidationPayload) -> RecordAPIResult<()>
+    {
+        let mut pending: Vec<(EconomicResourceAddress, EntryData)> = vec![];
+
+        for (idx, (event, new_resource)) in params.events.iter().enumerate() {
+            let result = match new_resource {
+                Some(resource) => prepare_inventory_creation(CreationPayload {
+                    event: event.to_owned(),
+                    resource: resource.to_owned(),
+                }).map(|_| ()),
+                None => simulate_inventory_update(&resource_entry_def_id, &event_entry_def_id, event, &mut pending),
+            };
+            if let Err(e) = result {
+                return Err(DataIntegrityError::InvalidRecord(format!("event {} in batch: {}", idx, e)));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Properties accessor for zome config
@@ -160,6 +244,70 @@ fn read_economic_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.economic_resource.index_zome)
 }
 
+/// Sync the `contains` / `contained_in` indexes with any change in a resource's container
+fn update_containment_index(identity_address: &EconomicResourceAddress, entry: &EntryData, prev_entry: &EntryData) -> RecordAPIResult<()> {
+    if entry.contained_in == prev_entry.contained_in {
+        return Ok(());
+    }
+    let now_contained = if let Some(contained) = &entry.contained_in { vec![contained.clone()] } else { vec![] };
+    let prev_contained = if let Some(contained) = &prev_entry.contained_in { vec![contained.clone()] } else { vec![] };
+    update_index!(Self(economic_resource(identity_address).contained_in(now_contained.as_slice()).not(prev_contained.as_slice())))?;
+    Ok(())
+}
+
+/// Validate the parameters of an event which creates a new resource, and build the resulting entry
+fn prepare_inventory_creation(params: CreationPayload) -> RecordAPIResult<EntryData>
+{
+    // :TODO: move this assertion to validation callback
+    if let MaybeUndefined::Some(_sent_inventory_id) = &params.get_event_params().resource_inventoried_as {
+        return Err(DataIntegrityError::RemoteRequestError("cannot create a new EconomicResource and specify an inventoried resource ID in the same event".to_string()));
+    }
+
+    let params = params.with_inventory_type(ResourceInventoryType::ProvidingInventory);  // inventories can only be inited by their owners initially
+    validate_inventory_event(params.get_event_params())?;
+
+    let new_entry = EntryData::try_from(params)?;
+    validate_inventory_balance(None, None, &new_entry)?;
+
+    Ok(new_entry)
+}
+
+/// Apply the effects of an event to a resource in memory, tracking the simulated state of
+/// each touched resource in `pending` so that subsequent events in a batch build upon it.
+fn simulate_inventory_update<S>(
+    resource_entry_def_id: S,
+    event_entry_def_id: S,
+    event: &EventCreateRequest,
+    pending: &mut Vec<(EconomicResourceAddress, EntryData)>,
+) -> RecordAPIResult<()>
+    where S: AsRef<str>,
+{
+    // same ordering as `update_inventory_from_event`: receiver first, then provider
+    let mut sides: Vec<(EconomicResourceAddress, ResourceInventoryType)> = vec![];
+    if let MaybeUndefined::Some(receiver_inventory) = &event.to_resource_inventoried_as {
+        sides.push((receiver_inventory.to_owned(), ResourceInventoryType::ReceivingInventory));
+    }
+    if let MaybeUndefined::Some(provider_inventory) = &event.resource_inventoried_as {
+        sides.push((provider_inventory.to_owned(), ResourceInventoryType::ProvidingInventory));
+    }
+
+    for (resource_address, inventory_type) in sides {
+        let prev_entry = match pending.iter().position(|(addr, _)| *addr == resource_address) {
+            Some(pos) => pending.remove(pos).1,
+            None => {
+                let (_, base_address, stored_entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&resource_entry_def_id, resource_address.as_ref())?;
+                let events = read_affecting_events(&event_entry_def_id, &base_address)?;
+                with_derived_quantities(&base_address, stored_entry, events.as_slice(), event.get_corrects().as_ref())?
+            },
+        };
+        let new_entry = prev_entry.try_update_with_event(&event.with_inventory_type(inventory_type))?;
+        validate_inventory_balance(Some(&resource_address), Some(&prev_entry), &new_entry)?;
+        pending.push((resource_address, new_entry));
+    }
+
+    Ok(())
+}
+
 fn handle_update_inventory_resource<S>(
     resource_entry_def_id: S,
     resource_addr: &RevisionHash,
@@ -167,7 +315,17 @@ fn handle_update_inventory_resource<S>(
 ) -> RecordAPIResult<(RevisionHash, EconomicResourceAddress, EntryData, EntryData)>
     where S: AsRef<str>,
 {
-    Ok(update_record(&resource_entry_def_id, resource_addr, event)?)
+    let (base_address, stored_entry): (EconomicResourceAddress, _) = read_record_entry_by_header::<EntryData, EntryStorage, _>(resource_addr)?;
+
+    // apply the event on top of the balance derived from all prior events, rather than the stored revision.
+    // If the event corrects an earlier one, that event's effects are netted out of the prior balance.
+    let events = read_affecting_events(EVENT_ENTRY_TYPE, &base_address)?;
+    let prev_entry = with_derived_quantities(&base_address, stored_entry, events.as_slice(), event.get_corrects().as_ref())?;
+    let new_entry = prev_entry.try_update_with_event(&event)?;
+    validate_inventory_balance(Some(&base_address), Some(&prev_entry), &new_entry)?;
+
+    let (revision_id, identity_address, new_entry, _stored_entry) = update_record(&resource_entry_def_id, resource_addr, new_entry)?;
+    Ok((revision_id, identity_address, new_entry, prev_entry))
 }
 
 fn handle_list_output<S>(event_entry_def_id: S, process_entry_def_id: S, entries_result: Vec<RecordAPIResult<(RevisionHash, EconomicResourceAddress, EntryData)>>) -> RecordAPIResult<Collection>
@@ -177,9 +335,11 @@ fn handle_list_output<S>(event_entry_def_id: S, process_entry_def_id: S, entries
         .cloned()
         .filter_map(Result::ok)
         .map(|(revision_id, entry_base_address, entry)| {
+            let events = read_affecting_events(&event_entry_def_id, &entry_base_address)?;
+            let entry = with_derived_quantities(&entry_base_address, entry, events.as_slice(), None)?;
             construct_list_response(
                 &entry_base_address, &revision_id, &entry,
-                get_link_fields(&event_entry_def_id, &process_entry_def_id, &entry_base_address)?
+                get_link_fields_from_events(&process_entry_def_id, &entry_base_address, events.as_slice())?
             )
         })
         .filter_map(Result::ok);
@@ -208,15 +368,19 @@ pub fn construct_response<'a>(
         stage,
         state,
         contains,
+        trace,
+        track,
      ): (
         Option<EconomicResourceAddress>,
         Option<ProcessSpecificationAddress>,
         Option<ActionId>,
         Vec<EconomicResourceAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
     ),
 ) -> RecordAPIResult<ResponseData> {
     Ok(ResponseData {
-        economic_resource: construct_response_record(address, revision_id, e, (contained_in, stage, state, contains))?
+        economic_resource: construct_response_record(address, revision_id, e, (contained_in, stage, state, contains, trace, track))?
     })
 }
 
@@ -227,11 +391,15 @@ pub fn construct_response_record<'a>(
         stage,
         state,
         contains,
+        trace,
+        track,
      ): (
         Option<EconomicResourceAddress>,
         Option<ProcessSpecificationAddress>,
         Option<ActionId>,
         Vec<EconomicResourceAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
     ),
 ) -> RecordAPIResult<Response> {
     Ok(Response {
@@ -254,6 +422,8 @@ pub fn construct_response_record<'a>(
         // link fields
         contained_in: contained_in.to_owned(),
         contains: contains.to_owned(),
+        trace: trace.to_owned(),
+        track: track.to_owned(),
     })
 }
 
@@ -263,16 +433,20 @@ pub fn construct_list_response<'a>(
         stage,
         state,
         contains,
+        trace,
+        track,
     ): (
         Option<EconomicResourceAddress>,
         Option<ProcessSpecificationAddress>,
         Option<ActionId>,
         Vec<EconomicResourceAddress>,
+        Vec<EconomicEventAddress>,
+        Vec<EconomicEventAddress>,
     )
 ) -> RecordAPIResult<Edge> {
     let record_cursor: Vec<u8> = address.to_owned().into();
     Ok(Edge {
-        node: construct_response(address, revision_id, e, (contained_in, stage, state, contains))?.economic_resource,
+        node: construct_response(address, revision_id, e, (contained_in, stage, state, contains, trace, track))?.economic_resource,
         // :TODO: use HoloHashb64 once API stabilises
         cursor: String::from_utf8(record_cursor).unwrap_or("".to_string())
     })
@@ -285,84 +459,124 @@ pub fn get_link_fields<'a, S>(event_entry_def_id: S, process_entry_def_id: S, re
     Option<ProcessSpecificationAddress>,
     Option<ActionId>,
     Vec<EconomicResourceAddress>,
+    Vec<EconomicEventAddress>,
+    Vec<EconomicEventAddress>,
+)>
+    where S: AsRef<str>
+{
+    let events = read_affecting_events(&event_entry_def_id, resource)?;
+    get_link_fields_from_events(&process_entry_def_id, resource, events.as_slice())
+}
+
+/// As above, for a resource whose affecting events have already been read
+fn get_link_fields_from_events<S>(process_entry_def_id: S, resource: &EconomicResourceAddress, events: &[(EconomicEventAddress, EventData)]) -> RecordAPIResult<(
+    Option<EconomicResourceAddress>,
+    Option<ProcessSpecificationAddress>,
+    Option<ActionId>,
+    Vec<EconomicResourceAddress>,
+    Vec<EconomicEventAddress>,
+    Vec<EconomicEventAddress>,
 )>
     where S: AsRef<str>
 {
+    let (trace, track) = get_resource_flows(resource, events);
     Ok((
         read_index!(economic_resource(resource).contained_in)?.pop(),
-        get_resource_stage(&event_entry_def_id, &process_entry_def_id, resource)?,
-        get_resource_state(&event_entry_def_id, resource)?,
+        get_resource_stage(&process_entry_def_id, events),
+        get_resource_state(events),
         read_index!(economic_resource(resource).contains)?,
+        trace,
+        track,
     ))
 }
 
-fn get_resource_state<S>(event_entry_def_id: S, resource: &EconomicResourceAddress) -> RecordAPIResult<Option<ActionId>>
-    where S: AsRef<str>
+/// Split the events affecting a resource into those which precede it (`trace`)
+/// and those which make use of it (`track`), one step in either direction.
+fn get_resource_flows(resource: &EconomicResourceAddress, events: &[(EconomicEventAddress, EventData)]) -> (Vec<EconomicEventAddress>, Vec<EconomicEventAddress>)
 {
-    let events: Vec<EconomicEventAddress> = get_affecting_events(resource)?;
+    let mut trace: Vec<EconomicEventAddress> = vec![];
+    let mut track: Vec<EconomicEventAddress> = vec![];
+
+    for (event_address, event) in events {
+        if event.is_incoming_to(resource) {
+            trace.push(event_address.to_owned());
+        } else if event.is_outgoing_from(resource) {
+            track.push(event_address.to_owned());
+        }
+    }
 
-    // grab the most recent "pass" or "fail" action
-    Ok(events.iter()
-        .rev()
-        .fold(None, move |result, event| {
-            // already found it, just fall through
-            // :TODO: figure out the Rust STL method to abort on first Some() value
-            if let Some(_) = result {
-                return result;
-            }
+    (trace, track)
+}
 
-            let evt = read_record_entry::<EventData, EventStorage, _,_>(&event_entry_def_id, event.as_ref());
-            match evt {
-                Err(_) => result, // :TODO: this indicates some data integrity error
-                Ok((_, _, entry)) => {
-                    match &*String::from(entry.action.clone()) {
-                        "pass" | "fail" => Some(entry.action),  // found it! Return this as the current resource state.
-                        _ => result,    // still not located, keep looking...
-                    }
-                },
-            }
+fn get_resource_state(events: &[(EconomicEventAddress, EventData)]) -> Option<ActionId>
+{
+    // grab the most recent action which alters resource state (eg. "pass" or "fail")
+    events.iter()
+        .rev()
+        .find(|(_, entry)| match get_builtin_action(entry.action.as_ref()) {
+            Some(action) => action.state_effect == StateEffect::Update,
+            None => false,
         })
-    )
+        .map(|(_, entry)| entry.action.to_owned())
 }
 
-fn get_resource_stage<S>(event_entry_def_id: S, process_entry_def_id: S, resource: &EconomicResourceAddress) -> RecordAPIResult<Option<ProcessSpecificationAddress>>
+fn get_resource_stage<S>(process_entry_def_id: S, events: &[(EconomicEventAddress, EventData)]) -> Option<ProcessSpecificationAddress>
     where S: AsRef<str>
 {
-    let events: Vec<EconomicEventAddress> = get_affecting_events(resource)?;
-
-    // grab the most recent event with a process output association
-    Ok(events.iter()
+    // grab the most recent stage-altering event with a process output association
+    events.iter()
         .rev()
-        .fold(None, move |result, event| {
-            // already found it, just fall through
-            // :TODO: figure out the Rust STL method to abort on first Some() value
-            if let Some(_) = result {
-                return result;
-            }
-
-            let evt = read_record_entry::<EventData, EventStorage, _,_>(&event_entry_def_id, event.as_ref());
-            match evt {
-                Err(_) => result, // :TODO: this indicates some data integrity error
-                Ok((_, _, entry)) => {
-                    match &entry.output_of {
-                        Some(output_of) => {
-                            // get the associated process
-                            let maybe_process_entry = read_record_entry::<ProcessData, ProcessStorage, _,_>(&process_entry_def_id, output_of.as_ref());
-                            // check to see if it has an associated specification
-                            match &maybe_process_entry {
-                                Ok((_,_, process_entry)) => match &process_entry.based_on {
-                                    Some(based_on) => Some(based_on.to_owned()),   // found it!
-                                    None => result, // still not located, keep looking...
-                                },
-                                Err(_) => result, // :TODO: this indicates some data integrity error
-                            }
-                        },
-                        None => result,    // still not located, keep looking...
+        .find_map(|(_, entry)| {
+            let alters_stage = match get_builtin_action(entry.action.as_ref()) {
+                Some(action) => action.stage_effect == StageEffect::Update,
+                None => false,
+            };
+            match &entry.output_of {
+                Some(output_of) if alters_stage => {
+                    // get the associated process, and check to see if it has an associated specification
+                    match read_record_entry::<ProcessData, ProcessStorage, _,_>(&process_entry_def_id, output_of.as_ref()) {
+                        Ok((_,_, process_entry)) => process_entry.based_on,
+                        Err(_) => None, // :TODO: this indicates some data integrity error
                     }
                 },
+                _ => None,    // still not located, keep looking...
             }
         })
-    )
+}
+
+/// Derive the quantities of an EconomicResource from the set of events affecting it.
+///
+/// Event effects are commutative increments & decrements (in the manner of a CRDT counter),
+/// so events authored concurrently against the same resource revision are all reflected in
+/// the balance, regardless of which stored revision "won". Stored quantities act as a cache
+/// and are only used where no events have been indexed against the resource.
+///
+/// If `pending_correction` is given, the effects of that event are netted out of the balance
+/// in advance of the correcting event being written.
+///
+fn with_derived_quantities(
+    resource: &EconomicResourceAddress, entry: EntryData,
+    events: &[(EconomicEventAddress, EventData)],
+    pending_correction: Option<&EconomicEventAddress>,
+) -> RecordAPIResult<EntryData>
+{
+    if events.is_empty() {
+        return Ok(entry);
+    }
+    let quantities = replay_inventory_events(resource, &entry, events, pending_correction)?;
+    Ok(entry.update_with(quantities))
+}
+
+/// Read all the EconomicEvents affecting a given EconomicResource
+fn read_affecting_events<S>(event_entry_def_id: S, resource: &EconomicResourceAddress) -> RecordAPIResult<Vec<(EconomicEventAddress, EventData)>>
+    where S: AsRef<str>
+{
+    get_affecting_events(resource)?.iter()
+        .map(|event_address| {
+            let (_, _, event) = read_record_entry::<EventData, EventStorage, _,_>(&event_entry_def_id, event_address.as_ref())?;
+            Ok((event_address.to_owned(), event))
+        })
+        .collect()
 }
 
 /// Read all the EconomicEvents affecting a given EconomicResource
@@ -9,6 +9,7 @@
 use holochain_serialized_bytes::prelude::*;
 
 use serde_maybe_undefined::MaybeUndefined;
+use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     RevisionHash, ByAddress,
     EconomicResourceAddress,
@@ -18,6 +19,7 @@ pub use vf_attributes_hdk::{
     ResourceSpecificationAddress,
     UnitId,
     ProductBatchAddress,
+    ProcessAddress,
 };
 
 use hc_zome_rea_economic_event_rpc::{
@@ -57,6 +59,13 @@ impl<'a> CreationPayload {
     }
 }
 
+/// Batch of events to be checked against inventory before any are written, in the order they
+/// will be applied. Events which create a new resource are paired with its creation parameters.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct InventoryValidationPayload {
+    pub events: Vec<(EventCreateRequest, Option<CreateRequest>)>,
+}
+
 //---------------- UPDATE REQUEST ----------------
 
 // used in EconomicResource API
@@ -91,6 +100,35 @@ pub struct UpdateParams {
     pub resource: UpdateRequest,
 }
 
+//---------------- RECALCULATION REQUEST ----------------
+
+/// Parameters for rebuilding an EconomicResource's quantities from its event log
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecalculateParams {
+    pub address: EconomicResourceAddress,
+    #[serde(default)]
+    pub write_correction: bool,
+    /// Event about to be removed from the log. Its effects are netted out of the result,
+    /// which must then also pass negative balance validation.
+    #[serde(default)]
+    pub excluding_event: Option<EconomicEventAddress>,
+}
+
+/// I/O struct to describe the result of replaying an EconomicResource's event log
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecalculationResponse {
+    pub id: EconomicResourceAddress,
+    pub revision_id: RevisionHash,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accounting_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onhand_quantity: Option<QuantityValue>,
+    pub discrepancies: Vec<String>,
+    pub corrected: bool,
+}
+
 //---------------- QUERY FILTER REQUEST ----------------
 
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
@@ -100,4 +138,8 @@ pub struct QueryParams {
     pub contained_in: Option<EconomicResourceAddress>,
     pub conforms_to: Option<ResourceSpecificationAddress>,
     pub affected_by: Option<EconomicEventAddress>,
+    #[serde(default)]
+    pub flows_into: Option<ProcessAddress>,
+    #[serde(default)]
+    pub flows_out_of: Option<ProcessAddress>,
 }
@@ -9,6 +9,7 @@
 use hdk::prelude::*;
 
 use hdk_records::{
+    DataIntegrityError, RecordAPIResult,
     MaybeUndefined, OtherCellResult,
     generate_record_entry,
     record_interface::Updateable,
@@ -18,6 +19,7 @@ use hdk_records::{
 use vf_measurement::*;
 use vf_attributes_hdk::{
     EconomicResourceAddress,
+    EconomicEventAddress,
     ExternalURL,
     LocationAddress,
     ResourceSpecificationAddress,
@@ -25,7 +27,7 @@ use vf_attributes_hdk::{
     ProductBatchAddress,
     ActionId,
 };
-use vf_actions::{ ActionEffect, ActionInventoryEffect, get_builtin_action };
+use vf_actions::{ ActionEffect, ActionInventoryEffect, LocationEffect, ContainedEffect, get_builtin_action };
 use hc_zome_rea_resource_specification_rpc::{ResponseData as ResourceSpecificationResponse};
 
 use hc_zome_rea_economic_resource_rpc::*;
@@ -33,6 +35,9 @@ use hc_zome_rea_economic_event_rpc::{
     CreateRequest as EventCreateRequest,
     ResourceInventoryType,
 };
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData,
+};
 
 // :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
 pub use hdk_records::record_interface::Identified;
@@ -72,6 +77,11 @@ impl EntryData {
         if !(self.classified_as.is_some() || self.conforms_to.is_some()) {
             return Err("EconomicResource must have either a specification or classification".into());
         }
+        if let (Some(accounting), Some(onhand)) = (&self.accounting_quantity, &self.onhand_quantity) {
+            if !units_match(accounting, onhand) {
+                return Err("EconomicResource accounting and onhand quantities must be recorded in the same unit".into());
+            }
+        }
         Ok(())
     }
 }
@@ -80,15 +90,37 @@ generate_record_entry!(EntryData, EconomicResourceAddress, EntryStorage);
 
 //---------------- CREATE ----------------
 
+/// Checks that an event can be applied to an EconomicResource, returning the side of
+/// the event the resource is on.
+///
+/// Called by `TryFrom<CreationPayload>` and `try_update_with_event`, so that events which
+/// cannot be applied fail rather than leaving the resource unchanged.
+///
+pub fn validate_inventory_event(e: &EventCreateRequest) -> RecordAPIResult<ResourceInventoryType> {
+    if let None = get_builtin_action(e.get_action()) {
+        return Err(DataIntegrityError::UnknownAction(e.get_action().to_string()));
+    }
+    match &e.target_inventory_type {
+        Some(inventory_type) => Ok(inventory_type.to_owned()),
+        None => Err(DataIntegrityError::MissingInternalParameter("EconomicEvent inventory type".to_string())),
+    }
+}
+
 /// Handles create operations via observed event resource inspection parameter
 /// @see https://github.com/holo-rea/holo-rea/issues/65
-impl From<CreationPayload> for EntryData
+///
+/// Fails if the event's action is unknown or its quantities cannot be applied.
+///
+impl TryFrom<CreationPayload> for EntryData
 {
-    fn from(t: CreationPayload) -> EntryData {
+    type Error = DataIntegrityError;
+
+    fn try_from(t: CreationPayload) -> RecordAPIResult<EntryData> {
         let conforming = t.get_resource_specification_id();
         let r = t.resource;
         let e = t.event;
-        EntryData {
+        let inventory_type = validate_inventory_event(&e)?;
+        Ok(EntryData {
             conforms_to: conforming.clone(),
             classified_as: if e.resource_classified_as == MaybeUndefined::Undefined { None } else { e.resource_classified_as.to_owned().to_option() },
             tracking_identifier: if r.tracking_identifier == MaybeUndefined::Undefined { None } else { r.tracking_identifier.to_owned().to_option() },
@@ -100,11 +132,8 @@ impl From<CreationPayload> for EntryData
                     e.resource_quantity.to_owned(),
                     &e.action,
                     ResourceValueType::AccountingValue,
-                    match &e.target_inventory_type {
-                        Some(inventory_type) => inventory_type.to_owned(),
-                        None => panic!("Developer error: EconomicEvent inventory type must be provided when creating EconomicResource!"),
-                    },
-                ),
+                    inventory_type.to_owned(),
+                )?,
                 _ => None,
             },
             onhand_quantity: match e.resource_quantity.to_owned() {
@@ -113,21 +142,20 @@ impl From<CreationPayload> for EntryData
                     e.resource_quantity.to_owned(),
                     &e.action,
                     ResourceValueType::OnhandValue,
-                    match &e.target_inventory_type {
-                        Some(inventory_type) => inventory_type.to_owned(),
-                        None => panic!("Developer error: EconomicEvent inventory type must be provided when updating EconomicResource!"),
-                    },
-                ),
+                    inventory_type.to_owned(),
+                )?,
                 _ => None,
             },
             unit_of_effort: match conforming {
                 Some(conforms_to_spec) => get_default_unit_for_specification(conforms_to_spec),
                 None => None,
             },
-            current_location: if r.current_location == MaybeUndefined::Undefined { None } else { r.current_location.to_owned().to_option() },
+            current_location: if r.current_location == MaybeUndefined::Undefined {
+                get_updated_location(None, &e.action, e.get_location(), true, &ResourceInventoryType::ProvidingInventory)
+            } else { r.current_location.to_owned().to_option() },
             contained_in: if r.contained_in == MaybeUndefined::Undefined { None } else { r.contained_in.to_owned().to_option() },
             note: if r.note == MaybeUndefined::Undefined { None } else { r.note.clone().into() },
-        }
+        })
     }
 }
 
@@ -151,6 +179,59 @@ fn get_default_unit_for_specification(specification_id: ResourceSpecificationAdd
     }
 }
 
+/// Reads the negative balance policy of a ResourceSpecification.
+/// Resources without a specification are unrestricted.
+fn get_allow_negative_for_specification(specification_id: &Option<ResourceSpecificationAddress>) -> RecordAPIResult<bool> {
+    match specification_id {
+        None => Ok(true),
+        Some(specification_id) => {
+            let spec_data: OtherCellResult<ResourceSpecificationResponse> = call_zome_method(
+                specification_id,
+                &String::from("read_resource_specification"),
+                GetSpecificationRequest { address: specification_id.to_owned() },
+            );
+            Ok(spec_data?.resource_specification.allow_negative)
+        },
+    }
+}
+
+/// Ensures an event does not drive the quantities of an EconomicResource below zero,
+/// unless the ResourceSpecification of the resource permits negative balances.
+///
+/// Quantities which were already negative prior to the event are only rejected if the
+/// event would reduce them further.
+///
+pub fn validate_inventory_balance(
+    resource_id: Option<&EconomicResourceAddress>,
+    prev_entry: Option<&EntryData>,
+    new_entry: &EntryData,
+) -> RecordAPIResult<()> {
+    let shortfalls: Vec<String> = vec![
+        ("accounting", prev_entry.and_then(|e| e.accounting_quantity.to_owned()), new_entry.accounting_quantity.to_owned()),
+        ("onhand", prev_entry.and_then(|e| e.onhand_quantity.to_owned()), new_entry.onhand_quantity.to_owned()),
+    ].into_iter()
+        .filter_map(|(qty_type, prev_qty, new_qty)| {
+            let new_val = new_qty.as_ref()?.get_numerical_value();
+            let prev_val = prev_qty.map_or(0.0, |q| q.get_numerical_value());
+            if new_val < 0.0 && new_val < prev_val {
+                Some(format!("{} quantity short by {} {:?}", qty_type, -new_val, new_qty.unwrap().get_unit()))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if shortfalls.is_empty() || get_allow_negative_for_specification(&new_entry.conforms_to)? {
+        return Ok(());
+    }
+
+    let resource_name = match resource_id {
+        Some(id) => format!("EconomicResource {:?}", id),
+        None => "new EconomicResource".to_string(),
+    };
+    Err(DataIntegrityError::InsufficientInventory(format!("{} cannot hold a negative balance: {}", resource_name, shortfalls.join(", "))))
+}
+
 //---------------- UPDATE ----------------
 
 /// Handles update operations for correcting data entry errors
@@ -172,17 +253,15 @@ impl Updateable<UpdateRequest> for EntryData {
     }
 }
 
-/// Handle update operations by observed events
-///
-/// :WARNING: we presume the event has already been determined to relate to the
-/// resource, and this method will panic if that is not the case.
-///
-/// Currently it is only called within `hdk_records::record_helpers::update_record`,
-/// where this check is already implicitly performed.
-///
-impl Updateable<EventCreateRequest> for EntryData {
-    fn update_with(&self, e: EventCreateRequest) -> EntryData {
-        EntryData {
+impl EntryData {
+    /// Compute the state of this resource after applying the given event, failing
+    /// if the event's action is unknown or its quantities cannot be combined.
+    ///
+    /// :WARNING: we presume the event has already been determined to relate to the resource.
+    pub fn try_update_with_event(&self, e: &EventCreateRequest) -> RecordAPIResult<EntryData> {
+        let inventory_type = validate_inventory_event(e)?;
+
+        Ok(EntryData {
             conforms_to: self.conforms_to.to_owned(),
             classified_as: {
                 if let MaybeUndefined::Some(classified_as) = e.resource_classified_as.to_owned() {
@@ -209,32 +288,104 @@ impl Updateable<EventCreateRequest> for EntryData {
             image: self.image.to_owned(),
             accounting_quantity: update_quantity(
                 self.accounting_quantity.to_owned(), e.resource_quantity.to_owned(),
-                &e.action, ResourceValueType::AccountingValue, match &e.target_inventory_type {
-                    Some(inventory_type) => inventory_type.to_owned(),
-                    None => panic!("Developer error: EconomicEvent inventory type must be provided when updating EconomicResource!"),
-                },
-            ),
+                &e.action, ResourceValueType::AccountingValue, inventory_type.to_owned(),
+            )?,
             onhand_quantity: update_quantity(
                 self.onhand_quantity.to_owned(), e.resource_quantity.to_owned(),
-                &e.action, ResourceValueType::OnhandValue, match &e.target_inventory_type {
-                    Some(inventory_type) => inventory_type.to_owned(),
-                    None => panic!("Developer error: EconomicEvent inventory type must be provided when updating EconomicResource!"),
-                },
-            ),
+                &e.action, ResourceValueType::OnhandValue, inventory_type.to_owned(),
+            )?,
             unit_of_effort: self.unit_of_effort.to_owned(), // :TODO: pull from e.resource_conforms_to.unit_of_effort
-            current_location: if e.get_action() == "move" {
-                if let MaybeUndefined::Some(at_location) = e.get_location() {
-                    Some(at_location)
-                } else {
-                    self.current_location.to_owned()
-                }
-            } else { self.current_location.to_owned() },
-            contained_in: self.contained_in.to_owned(),
+            current_location: get_updated_location(self.current_location.to_owned(), &e.action, e.get_location(), false, &inventory_type),
+            contained_in: get_updated_container(self.contained_in.to_owned(), e, &inventory_type),
             note: self.note.to_owned(),
+        })
+    }
+}
+
+/// Replaces an entry with a fully computed successor revision. Used where updates are
+/// derived from more than the previous stored revision (eg. inventory balances reduced
+/// from the event log).
+impl Updateable<EntryData> for EntryData {
+    fn update_with(&self, e: EntryData) -> EntryData {
+        e
+    }
+}
+
+//---------------- RECALCULATION ----------------
+
+/// Internal payload for writing corrected quantities to an EconomicResource
+#[derive(Clone, Debug)]
+pub struct QuantityCorrection {
+    pub accounting_quantity: Option<QuantityValue>,
+    pub onhand_quantity: Option<QuantityValue>,
+}
+
+impl Updateable<QuantityCorrection> for EntryData {
+    fn update_with(&self, e: QuantityCorrection) -> EntryData {
+        EntryData {
+            accounting_quantity: e.accounting_quantity,
+            onhand_quantity: e.onhand_quantity,
+            ..self.to_owned()
         }
     }
 }
 
+/// Rebuild the accounting & onhand quantities of an EconomicResource by replaying the
+/// events which affect it through the same action effect logic used for incremental updates.
+///
+/// Event effects are commutative, so events may be provided in any order. Quantities are
+/// initialised from zero, in the units of the `stored` revision of the resource; a resource
+/// stored without quantities has none to rebuild.
+///
+/// Any event which has been corrected by another event in the log (or by `pending_correction`,
+/// for a correction which has not yet been written) is skipped, so that only the effects of
+/// the correcting event are counted.
+///
+pub fn replay_inventory_events(
+    resource: &EconomicResourceAddress,
+    stored: &EntryData,
+    events: &[(EconomicEventAddress, EventData)],
+    pending_correction: Option<&EconomicEventAddress>,
+) -> RecordAPIResult<QuantityCorrection> {
+    let mut accounting_quantity: Option<QuantityValue> = stored.accounting_quantity.as_ref()
+        .map(|qty| QuantityValue::new(0.0, qty.get_unit()));
+    let mut onhand_quantity: Option<QuantityValue> = stored.onhand_quantity.as_ref()
+        .map(|qty| QuantityValue::new(0.0, qty.get_unit()));
+
+    let corrected: Vec<&EconomicEventAddress> = events.iter()
+        .filter_map(|(_, event)| event.corrects.as_ref())
+        .chain(pending_correction)
+        .collect();
+
+    for (event_address, event) in events.iter() {
+        if corrected.contains(&event_address) {
+            continue;
+        }
+
+        // receiving side is applied first, as in live inventory updates
+        let mut sides: Vec<ResourceInventoryType> = vec![];
+        if event.to_resource_inventoried_as.as_ref() == Some(resource) {
+            sides.push(ResourceInventoryType::ReceivingInventory);
+        }
+        if event.resource_inventoried_as.as_ref() == Some(resource) {
+            sides.push(ResourceInventoryType::ProvidingInventory);
+        }
+
+        for inventory_type in sides {
+            accounting_quantity = update_quantity(
+                accounting_quantity, event.resource_quantity.to_owned().into(),
+                &event.action, ResourceValueType::AccountingValue, inventory_type.to_owned(),
+            )?;
+            onhand_quantity = update_quantity(
+                onhand_quantity, event.resource_quantity.to_owned().into(),
+                &event.action, ResourceValueType::OnhandValue, inventory_type,
+            )?;
+        }
+    }
+
+    Ok(QuantityCorrection { accounting_quantity, onhand_quantity })
+}
+
 /// Encapsulates the logic for updating EconomicResource quantities in response to event triggers
 fn update_quantity(
     current_val: Option<QuantityValue>,
@@ -242,23 +393,27 @@ fn update_quantity(
     action: &ActionId,
     which_qty_type: ResourceValueType,
     which_inventory_type: ResourceInventoryType,
-) -> Option<QuantityValue> {
+) -> RecordAPIResult<Option<QuantityValue>> {
     if None == current_val {
-        return None;
+        return Ok(None);
     }
     if MaybeUndefined::None == event_val || MaybeUndefined::Undefined == event_val {
-        return current_val;
+        return Ok(current_val);
     }
     let current = current_val.unwrap();
     let event_qty = event_val.unwrap();
 
-    let action_to_perform = get_event_action(action, which_qty_type, which_inventory_type);
+    let action_to_perform = get_event_action(action, which_qty_type, which_inventory_type)?;
 
-    match action_to_perform {
-        ActionInventoryEffect::NoEffect => Some(current),
-        ActionInventoryEffect::Increment => Some(add(current, event_qty)),
-        ActionInventoryEffect::Decrement => Some(subtract(current, event_qty)),
-    }
+    let result = match action_to_perform {
+        ActionInventoryEffect::NoEffect => Ok(current),
+        ActionInventoryEffect::Increment => add(current, event_qty),
+        ActionInventoryEffect::Decrement => subtract(current, event_qty),
+    };
+
+    result
+        .map(Some)
+        .map_err(|e| DataIntegrityError::UnitMismatch(e.to_string()))
 }
 
 enum ResourceValueType {
@@ -269,58 +424,94 @@ enum ResourceValueType {
 /// Determines the `ActionInventoryEffect` to apply to a resource, based on the input event
 /// action type, the type of inventory quantity ("accounting" or "on hand"),
 /// and the side of the event that the resource is on (providing or receiving).
+///
+/// Effects are read from the `onhandEffect` & `accountingEffect` of the action definition,
+/// and reversed for the receiving side of the event.
+///
 fn get_event_action(
     action: &ActionId,
     which_qty_type: ResourceValueType,
     which_inventory_type: ResourceInventoryType,
-) -> ActionInventoryEffect {
+) -> RecordAPIResult<ActionInventoryEffect> {
     let action_str: &str = (*action).as_ref();
 
     match get_builtin_action(action_str) {
-        Some(action_obj) => match &action_str[..] {
-            // 'transfer-custody' updates onHand but not Accounting
-            "transfer-custody" => match which_qty_type {
-                ResourceValueType::AccountingValue => ActionInventoryEffect::NoEffect,
-                ResourceValueType::OnhandValue => match which_inventory_type {
-                    ResourceInventoryType::ProvidingInventory => ActionInventoryEffect::Decrement,
-                    ResourceInventoryType::ReceivingInventory => ActionInventoryEffect::Increment,
+        Some(action_obj) => {
+            let effect = match which_qty_type {
+                ResourceValueType::AccountingValue => action_obj.accounting_effect,
+                ResourceValueType::OnhandValue => action_obj.onhand_effect,
+            };
+            Ok(match which_inventory_type {
+                ResourceInventoryType::ProvidingInventory => match effect {
+                    ActionEffect::DecrementIncrement => ActionInventoryEffect::Decrement,
+                    ActionEffect::NoEffect => ActionInventoryEffect::NoEffect,
+                    ActionEffect::Increment => ActionInventoryEffect::Increment,
+                    ActionEffect::Decrement => ActionInventoryEffect::Decrement,
                 },
-            },
-            // 'transfer-all-rights' updates Accounting but not onHand
-            "transfer-all-rights" => match which_qty_type {
-                ResourceValueType::AccountingValue => match which_inventory_type {
-                    ResourceInventoryType::ProvidingInventory => ActionInventoryEffect::Decrement,
-                    ResourceInventoryType::ReceivingInventory => ActionInventoryEffect::Increment,
+                ResourceInventoryType::ReceivingInventory => match effect {
+                    ActionEffect::DecrementIncrement => ActionInventoryEffect::Increment,
+                    ActionEffect::NoEffect => ActionInventoryEffect::NoEffect,
+                    ActionEffect::Increment => ActionInventoryEffect::Decrement,
+                    ActionEffect::Decrement => ActionInventoryEffect::Increment,
                 },
-                ResourceValueType::OnhandValue => ActionInventoryEffect::NoEffect,
-            },
-            // 'transfer' is a full swap
-            "transfer" => match which_inventory_type {
-                ResourceInventoryType::ProvidingInventory => ActionInventoryEffect::Decrement,
-                ResourceInventoryType::ReceivingInventory => ActionInventoryEffect::Increment,
-            },
-            // 'normal' action, just work from the configured effect and reverse for the receiver
-            _ => {
-                match which_inventory_type {
-                    ResourceInventoryType::ProvidingInventory => match action_obj.resource_effect {
-                        ActionEffect::DecrementIncrement => ActionInventoryEffect::Decrement,
-                        ActionEffect::NoEffect => ActionInventoryEffect::NoEffect,
-                        ActionEffect::Increment => ActionInventoryEffect::Increment,
-                        ActionEffect::Decrement => ActionInventoryEffect::Decrement,
-                    },
-                    ResourceInventoryType::ReceivingInventory => match action_obj.resource_effect {
-                        ActionEffect::DecrementIncrement => ActionInventoryEffect::Increment,
-                        ActionEffect::NoEffect => ActionInventoryEffect::NoEffect,
-                        ActionEffect::Increment => ActionInventoryEffect::Decrement,
-                        ActionEffect::Decrement => ActionInventoryEffect::Increment,
-                    },
-                }
-            }
+            })
         },
-        None => {
-            let mut err_string: String = "unknown EconomicEvent action type: ".to_string();
-            err_string.push_str(action.as_ref());
-            panic!("{:?}", err_string);
-        }
+        None => Err(DataIntegrityError::UnknownAction(action_str.to_string())),
+    }
+}
+
+/// Determines the `currentLocation` of a resource after an event, based on the
+/// `locationEffect` of the event's action and the side of the event the resource is on.
+fn get_updated_location(
+    current_location: Option<LocationAddress>,
+    action: &ActionId,
+    event_location: MaybeUndefined<LocationAddress>,
+    is_new_inventory: bool,
+    which_inventory_type: &ResourceInventoryType,
+) -> Option<LocationAddress> {
+    let location_effect = match get_builtin_action((*action).as_ref()) {
+        Some(action_obj) => action_obj.location_effect,
+        None => LocationEffect::NoEffect,
+    };
+    let applies = match location_effect {
+        LocationEffect::NoEffect => false,
+        LocationEffect::New => is_new_inventory,
+        LocationEffect::Update => true,
+        LocationEffect::UpdateTo => match which_inventory_type {
+            ResourceInventoryType::ReceivingInventory => true,
+            ResourceInventoryType::ProvidingInventory => false,
+        },
+    };
+
+    match (applies, event_location) {
+        (true, MaybeUndefined::Some(at_location)) => Some(at_location),
+        _ => current_location,
+    }
+}
+
+/// Determines the `containedIn` of a resource after an event, based on the
+/// `containedEffect` of the event's action. Only the providing side of the event
+/// (the resource being combined or separated) is affected; the receiving side of
+/// a `combine` event is the container itself.
+fn get_updated_container(
+    current_container: Option<EconomicResourceAddress>,
+    event: &EventCreateRequest,
+    which_inventory_type: &ResourceInventoryType,
+) -> Option<EconomicResourceAddress> {
+    if let ResourceInventoryType::ReceivingInventory = which_inventory_type {
+        return current_container;
+    }
+    let contained_effect = match get_builtin_action(event.get_action()) {
+        Some(action_obj) => action_obj.contained_effect,
+        None => ContainedEffect::NoEffect,
+    };
+
+    match contained_effect {
+        ContainedEffect::NoEffect => current_container,
+        ContainedEffect::Update => match &event.to_resource_inventoried_as {
+            MaybeUndefined::Some(container) => Some(container.to_owned()),
+            _ => current_container,
+        },
+        ContainedEffect::Remove => None,
     }
 }
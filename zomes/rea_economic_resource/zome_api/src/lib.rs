@@ -19,6 +19,8 @@ pub trait API {
     fn get_economic_resource(entry_def_id: Self::S, event_entry_def_id: Self::S, process_entry_def_id: Self::S, address: EconomicResourceAddress) -> RecordAPIResult<ResponseData>;
     fn update_economic_resource(entry_def_id: Self::S, event_entry_def_id: Self::S, process_entry_def_id: Self::S, resource: UpdateRequest) -> RecordAPIResult<ResponseData>;
     fn get_all_economic_resources(entry_def_id: Self::S, event_entry_def_id: Self::S, process_entry_def_id: Self::S) -> RecordAPIResult<Collection>;
+    fn recalculate_economic_resource(entry_def_id: Self::S, event_entry_def_id: Self::S, params: RecalculateParams) -> RecordAPIResult<RecalculationResponse>;
+    fn validate_inventory_effects(resource_entry_def_id: Self::S, event_entry_def_id: Self::S, params: InventoryValidationPayload) -> RecordAPIResult<()>;
 }
 
 /// Macro to programatically and predictably bind an `API` implementation to a
@@ -47,6 +49,12 @@ macro_rules! declare_economic_resource_zome_api {
             Ok(<$zome_api>::update_inventory_from_event(RESOURCE_ENTRY_TYPE, event)?)
         }
 
+        #[hdk_extern]
+        fn _internal_validate_inventory(params: InventoryValidationPayload) -> ExternResult<()>
+        {
+            Ok(<$zome_api>::validate_inventory_effects(RESOURCE_ENTRY_TYPE, EVENT_ENTRY_TYPE, params)?)
+        }
+
         #[hdk_extern]
         fn get_economic_resource(ByAddress { address }: ByAddress<EconomicResourceAddress>) -> ExternResult<$crate::ResponseData> {
             Ok(<$zome_api>::get_economic_resource(
@@ -67,6 +75,11 @@ macro_rules! declare_economic_resource_zome_api {
         fn get_all_economic_resources(_: ()) -> ExternResult<$crate::Collection> {
             Ok(<$zome_api>::get_all_economic_resources(RESOURCE_ENTRY_TYPE, EVENT_ENTRY_TYPE, PROCESS_ENTRY_TYPE)?)
         }
+
+        #[hdk_extern]
+        fn recalculate_economic_resource(params: RecalculateParams) -> ExternResult<RecalculationResponse> {
+            Ok(<$zome_api>::recalculate_economic_resource(RESOURCE_ENTRY_TYPE, EVENT_ENTRY_TYPE, params)?)
+        }
     };
 }
 
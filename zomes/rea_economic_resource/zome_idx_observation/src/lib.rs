@@ -16,4 +16,6 @@ struct EconomicResource {
 
     // internal indexes (not part of REA spec)
     affected_by: Local<economic_event, affects>,
+    flows_into: Local<process, input_resources>,
+    flows_out_of: Local<process, output_resources>,
 }
@@ -7,21 +7,44 @@
  * @package Holo-REA
  */
 use paste::paste;
+use hdk::prelude::{Serialize, Deserialize};
 use hdk_records::{
     RecordAPIResult,
+    OtherCellResult,
     MaybeUndefined,
     records::{
         create_record,
+        read_existing_record,
+        create_idempotency_key_index,
         read_record_entry,
         read_record_entry_by_header,
         update_record,
         delete_record,
     },
+    rpc::{
+        call_zome_method,
+        call_local_zome_method,
+    },
 };
 use hdk_semantic_indexes_client_lib::*;
 
+use hc_zome_rea_intent_storage_consts::*;
 use hc_zome_rea_intent_storage::*;
 use hc_zome_rea_intent_rpc::*;
+use hc_zome_rea_intent_rpc::{
+    ResponseData as IntentResponse,
+    QueryParams as IntentQueryParams,
+};
+use hc_zome_rea_commitment_rpc::{
+    ResponseData as CommitmentResponse,
+    QueryParams as CommitmentQueryParams,
+};
+use hc_zome_rea_process_rpc::{
+    IndexFlowsParams,
+    FlowsUpdate,
+    PlannedFlow,
+    PlannedFlowAddress,
+};
 
 // :SHONK: needed to re-export for zome `entry_defs()` where macro-assigned defs are overridden
 pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
@@ -29,14 +52,23 @@ pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
 pub fn handle_create_intent<S>(entry_def_id: S, intent: CreateRequest) -> RecordAPIResult<ResponseData>
     where S: AsRef<str>,
 {
+    // if a record has already been created with this idempotency key (eg. a retried request), return it without re-indexing
+    let idempotency_key: Option<String> = intent.idempotency_key.to_owned().into();
+    if let Some((header_addr, base_address, entry_resp)) = read_existing_record::<EntryData, EntryStorage, IntentAddress, _>(&entry_def_id, &idempotency_key)? {
+        return construct_response(&base_address, &header_addr, &entry_resp, get_link_fields(&base_address)?);
+    }
+
     let (header_addr, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, intent.to_owned())?;
+    create_idempotency_key_index(&entry_def_id, &idempotency_key, &base_address)?;
 
     // handle link fields
     if let CreateRequest { input_of: MaybeUndefined::Some(input_of), .. } = &intent {
         create_index!(Remote(intent.input_of(input_of), process.intended_inputs(&base_address)))?;
+        let _ = request_index_process_flows(input_of, &base_address, Some(&entry_resp))?;
     };
     if let CreateRequest { output_of: MaybeUndefined::Some(output_of), .. } = &intent {
         create_index!(Remote(intent.output_of(output_of), process.intended_outputs(&base_address)))?;
+        let _ = request_index_process_flows(output_of, &base_address, Some(&entry_resp))?;
     };
 
     // return entire record structure
@@ -77,6 +109,24 @@ pub fn handle_update_intent<S>(entry_def_id: S, intent: UpdateRequest) -> Record
             process.intended_outputs(&base_address)
         ))?;
     }
+    if new_entry.input_of != prev_entry.input_of
+        || new_entry.output_of != prev_entry.output_of
+        || new_entry.resource_inventoried_as != prev_entry.resource_inventoried_as
+        || new_entry.resource_conforms_to != prev_entry.resource_conforms_to
+    {
+        // reindex the Processes this intent has left as well as those it now belongs to
+        let mut processes: Vec<ProcessAddress> = vec![];
+        let all_processes = prev_entry.input_of.iter().chain(prev_entry.output_of.iter())
+            .chain(new_entry.input_of.iter()).chain(new_entry.output_of.iter());
+        for process_address in all_processes {
+            if !processes.contains(process_address) {
+                processes.push(process_address.to_owned());
+            }
+        }
+        for process_address in processes.iter() {
+            let _ = request_index_process_flows(process_address, &base_address, Some(&new_entry))?;
+        }
+    }
 
     construct_response(&base_address, &revision_id, &new_entry, get_link_fields(&base_address)?)
 }
@@ -85,6 +135,7 @@ pub fn handle_delete_intent(revision_id: RevisionHash) -> RecordAPIResult<bool>
 {
     // load the record to ensure it is of the correct type
     let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+    let flow_processes: Vec<ProcessAddress> = entry.input_of.iter().chain(entry.output_of.iter()).cloned().collect();
 
     // handle link fields
     if let Some(process_address) = entry.input_of {
@@ -95,7 +146,134 @@ pub fn handle_delete_intent(revision_id: RevisionHash) -> RecordAPIResult<bool>
     }
 
     // delete entry last, as it must be present in order for links to be removed
-    delete_record::<EntryStorage, _>(&revision_id)
+    let deleted = delete_record::<EntryStorage, _>(&revision_id)?;
+
+    for process_address in flow_processes.iter() {
+        let _ = request_index_process_flows(process_address, &base_address, None)?;
+    }
+
+    Ok(deleted)
+}
+
+/// Notify the (possibly remote) Process zome that the planned inputs or outputs of a Process have changed.
+///
+/// All Commitments & Intents which are inputs or outputs of the Process are read from this DNA and sent
+/// along, so that the Process zome does not need to call back into planning. `current` holds the values
+/// of the Intent `intent` as written in this call, which cannot be read back until this call's writes are committed.
+///
+/// As with other indexes spanning DNAs (@see `create_remote_index`), failure to reach the Process's DNA
+/// does not fail the write, and is returned for the caller to ignore or report. Failures to read from this
+/// DNA are errors. The Process recomputes its indexes from all of its planned flows each time, so a missed
+/// notification is corrected by the next change to any planned flow of the same Process.
+///
+fn request_index_process_flows(process: &ProcessAddress, intent: &IntentAddress, current: Option<&EntryData>) -> RecordAPIResult<OtherCellResult<()>>
+{
+    let (mut inputs, mut outputs) = read_planned_flows(process)?;
+
+    let flow_address = PlannedFlowAddress::Intent(intent.to_owned());
+    inputs.retain(|flow| flow.address != flow_address);
+    outputs.retain(|flow| flow.address != flow_address);
+    if let Some(entry) = current {
+        if entry.input_of.as_ref() == Some(process) {
+            inputs.push(planned_flow(intent, entry));
+        }
+        if entry.output_of.as_ref() == Some(process) {
+            outputs.push(planned_flow(intent, entry));
+        }
+    }
+
+    Ok(call_zome_method(
+        process,
+        &PROCESS_FLOWS_INDEXING_API_METHOD,
+        IndexFlowsParams { address: process.to_owned(), update: FlowsUpdate::PlannedFlowsChanged { inputs, outputs } },
+    ))
+}
+
+/// Read the Commitments & Intents which are (respectively) inputs and outputs of a Process
+fn read_planned_flows(process: &ProcessAddress) -> RecordAPIResult<(Vec<PlannedFlow>, Vec<PlannedFlow>)> {
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+
+    for (is_input, commitments) in [(true, query_commitments(process, true)?), (false, query_commitments(process, false)?)] {
+        for CommitmentResponse { commitment } in commitments {
+            let flow = PlannedFlow {
+                address: PlannedFlowAddress::Commitment(commitment.id),
+                action: commitment.action,
+                provider: Some(commitment.provider),
+                resource_inventoried_as: commitment.resource_inventoried_as,
+                resource_conforms_to: commitment.resource_conforms_to,
+            };
+            if is_input { inputs.push(flow) } else { outputs.push(flow) }
+        }
+    }
+    for (is_input, intents) in [(true, query_intents(process, true)?), (false, query_intents(process, false)?)] {
+        for IntentResponse { intent } in intents {
+            let flow = PlannedFlow {
+                address: PlannedFlowAddress::Intent(intent.id),
+                action: intent.action,
+                provider: intent.provider,
+                resource_inventoried_as: intent.resource_inventoried_as,
+                resource_conforms_to: intent.resource_conforms_to,
+            };
+            if is_input { inputs.push(flow) } else { outputs.push(flow) }
+        }
+    }
+
+    Ok((inputs, outputs))
+}
+
+fn query_commitments(process: &ProcessAddress, is_input: bool) -> RecordAPIResult<Vec<CommitmentResponse>> {
+    let resp: QueryResults<CommitmentResponse> = call_local_zome_method(
+        read_commitment_index_zome,
+        COMMITMENT_QUERY_API_METHOD.to_string(),
+        QueryInputs { params: CommitmentQueryParams {
+            input_of: if is_input { Some(process.to_owned()) } else { None },
+            output_of: if is_input { None } else { Some(process.to_owned()) },
+            fulfilled_by: None,
+            satisfies: None,
+            clause_of: None,
+            independent_demand_of: None,
+            planned_within: None,
+        } },
+    )?;
+    Ok(resp.results)
+}
+
+fn query_intents(process: &ProcessAddress, is_input: bool) -> RecordAPIResult<Vec<IntentResponse>> {
+    let resp: QueryResults<IntentResponse> = call_local_zome_method(
+        read_intent_index_zome,
+        INTENT_QUERY_API_METHOD.to_string(),
+        QueryInputs { params: IntentQueryParams {
+            input_of: if is_input { Some(process.to_owned()) } else { None },
+            output_of: if is_input { None } else { Some(process.to_owned()) },
+            satisfied_by: None,
+            proposed_in: None,
+        } },
+    )?;
+    Ok(resp.results)
+}
+
+/// Parameters for the Commitment & Intent index zome query APIs
+#[derive(Debug, Serialize)]
+struct QueryInputs<T> {
+    params: T,
+}
+
+/// Results of the Commitment & Intent index zome query APIs
+#[derive(Debug, Deserialize)]
+struct QueryResults<T> {
+    #[serde(default)]
+    results: Vec<T>,
+}
+
+fn planned_flow(address: &IntentAddress, entry: &EntryData) -> PlannedFlow {
+    PlannedFlow {
+        address: PlannedFlowAddress::Intent(address.to_owned()),
+        action: entry.action.to_owned(),
+        provider: entry.provider.to_owned(),
+        resource_inventoried_as: entry.resource_inventoried_as.to_owned(),
+        resource_conforms_to: entry.resource_conforms_to.to_owned(),
+    }
 }
 
 /// Create response from input DHT primitives
@@ -146,6 +324,11 @@ fn read_intent_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.intent.index_zome)
 }
 
+/// Properties accessor for zome config
+fn read_commitment_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.intent.commitment_index_zome
+}
+
 // @see construct_response
 pub fn get_link_fields(intent: &IntentAddress) -> RecordAPIResult<(
     Vec<SatisfactionAddress>,
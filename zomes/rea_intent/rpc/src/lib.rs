@@ -14,7 +14,7 @@ use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     ActionId,
     ExternalURL,
-    DateTime, FixedOffset,
+    DateTimeValue,
     ProcessAddress,
     AgentAddress,
     EconomicResourceAddress,
@@ -62,13 +62,13 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub available_quantity: Option<QuantityValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_beginning: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_end: Option<DateTime<FixedOffset>>,
+    pub has_end: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
+    pub has_point_in_time: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub due: Option<DateTime<FixedOffset>>,
+    pub due: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub at_location: Option<LocationAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,13 +126,13 @@ pub struct CreateRequest {
     #[serde(default)]
     pub available_quantity: MaybeUndefined<QuantityValue>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_point_in_time: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_point_in_time: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub due: MaybeUndefined<DateTime<FixedOffset>>,
+    pub due: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub at_location: MaybeUndefined<LocationAddress>,
     #[serde(default)]
@@ -141,6 +141,8 @@ pub struct CreateRequest {
     pub finished: MaybeUndefined<bool>,
     #[serde(default)]
     pub in_scope_of: MaybeUndefined<Vec<String>>,
+    #[serde(default)]
+    pub idempotency_key: MaybeUndefined<String>,
 }
 
 impl<'a> CreateRequest {
@@ -182,13 +184,13 @@ pub struct UpdateRequest {
     #[serde(default)]
     pub available_quantity: MaybeUndefined<QuantityValue>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_point_in_time: MaybeUndefined<DateTime<FixedOffset>>,
+    pub has_point_in_time: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub due: MaybeUndefined<DateTime<FixedOffset>>,
+    pub due: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub at_location: MaybeUndefined<LocationAddress>,
     #[serde(default)]
@@ -18,7 +18,7 @@ use vf_measurement::QuantityValue;
 pub use vf_attributes_hdk::{
     RevisionHash,
     ActionId,
-    DateTime, FixedOffset,
+    DateTimeValue,
     ExternalURL,
     IntentAddress,
     LocationAddress,
@@ -28,6 +28,7 @@ pub use vf_attributes_hdk::{
     ResourceSpecificationAddress,
 };
 
+use vf_attributes_hdk::validate_date_order;
 use vf_actions::{ validate_flow_action };
 
 use hc_zome_rea_intent_rpc::{ CreateRequest, UpdateRequest };
@@ -46,6 +47,7 @@ pub struct DnaConfigSlice {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
 pub struct IntentZomeConfig {
     pub index_zome: String,
+    pub commitment_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -63,21 +65,27 @@ pub struct EntryData {
     pub resource_quantity: Option<QuantityValue>,
     pub effort_quantity: Option<QuantityValue>,
     pub available_quantity: Option<QuantityValue>,
-    pub has_beginning: Option<DateTime<FixedOffset>>,
-    pub has_end: Option<DateTime<FixedOffset>>,
-    pub has_point_in_time: Option<DateTime<FixedOffset>>,
-    pub due: Option<DateTime<FixedOffset>>,
+    pub has_beginning: Option<DateTimeValue>,
+    pub has_end: Option<DateTimeValue>,
+    pub has_point_in_time: Option<DateTimeValue>,
+    pub due: Option<DateTimeValue>,
     pub at_location: Option<LocationAddress>,
     pub agreed_in: Option<ExternalURL>,
     pub finished: bool,
     pub in_scope_of: Option<Vec<String>>,
     pub image: Option<ExternalURL>,
     pub note: Option<String>,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 impl EntryData {
     pub fn validate_action(&self) -> Result<(), String> {
-        validate_flow_action(self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned())
+        validate_flow_action("Intent", self.action.to_owned(), self.input_of.to_owned(), self.output_of.to_owned())
+    }
+
+    pub fn validate_dates(&self) -> Result<(), String> {
+        validate_date_order(&self.has_beginning, "hasBeginning", &self.has_end, "hasEnd")
     }
 
     pub fn validate_or_fields(&self) -> Result<(), String> {
@@ -117,6 +125,7 @@ impl From<CreateRequest> for EntryData {
             agreed_in: e.agreed_in.to_owned().into(),
             finished: e.finished.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_false" binding via Serde
             in_scope_of: e.in_scope_of.to_owned().into(),
+            idempotency_key: e.idempotency_key.into(),
         }
     }
 }
@@ -146,6 +155,7 @@ impl Updateable<UpdateRequest> for EntryData {
             agreed_in: if e.agreed_in == MaybeUndefined::Undefined { self.agreed_in.to_owned() } else { e.agreed_in.to_owned().into() },
             finished: if e.finished == MaybeUndefined::Undefined { self.finished.to_owned() } else { e.finished.to_owned().to_option().unwrap() },
             in_scope_of: if e.in_scope_of== MaybeUndefined::Undefined { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
+            idempotency_key: self.idempotency_key.to_owned(),
             image: if e.image== MaybeUndefined::Undefined { self.image.to_owned() } else { e.image.to_owned().into() },
             note: if e.note== MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
         }
@@ -21,3 +21,7 @@ pub const PROCESS_INPUT_INDEXING_API_METHOD: &str = "index_process_input_intents
 pub const INTENT_OUTPUT_READ_API_METHOD: &str = "_internal_read_intent_process_outputs";
 pub const INTENT_OUTPUT_INDEXING_API_METHOD: &str = "_internal_reindex_process_outputs";
 pub const PROCESS_OUTPUT_INDEXING_API_METHOD: &str = "index_process_output_intents";
+
+pub const PROCESS_FLOWS_INDEXING_API_METHOD: &str = "index_process_flows";
+pub const COMMITMENT_QUERY_API_METHOD: &str = "query_commitments";
+pub const INTENT_QUERY_API_METHOD: &str = "query_intents";
@@ -0,0 +1,546 @@
+/**
+ * Holo-REA plan zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Plan` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk::prelude::{Serialize, Deserialize, sys_time};
+use hdk_records::{
+    RecordAPIResult, DataIntegrityError,
+    OtherCellResult, MaybeUndefined,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+    rpc::{
+        call_zome_method,
+        call_local_zome_method,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+use hdk_semantic_indexes_zome_rpc::{RemoteEntryLinkRequest, RemoteEntryLinkResponse};
+use vf_actions::{ ProcessType, get_builtin_action, is_work };
+
+use hc_zome_rea_plan_storage_consts::*;
+use hc_zome_rea_plan_storage::*;
+use hc_zome_rea_plan_rpc::*;
+use hc_zome_rea_process_rpc::{
+    CreateRequest as ProcessCreateRequest,
+    ResponseData as ProcessResponse,
+};
+use hc_zome_rea_commitment_rpc::{
+    CreateRequest as CommitmentCreateRequest,
+    ResponseData as CommitmentResponse,
+};
+use hc_zome_rea_recipe_flow_rpc::{
+    ByAddress,
+    RecipeFlowAddress,
+    RecipeProcessAddress,
+    RecipeResourceAddress,
+    ActionId,
+    Response as RecipeFlow,
+    ResponseData as RecipeFlowResponse,
+    QueryParams as RecipeFlowQueryParams,
+};
+use hc_zome_rea_recipe_process_rpc::{
+    Response as RecipeProcess,
+    ResponseData as RecipeProcessResponse,
+};
+use hc_zome_rea_recipe_resource_rpc::{
+    Response as RecipeResource,
+    ResponseData as RecipeResourceResponse,
+};
+
+pub use hc_zome_rea_plan_storage::PLAN_ENTRY_TYPE;
+
+// :SHONK: needed to re-export for zome `entry_defs()` where macro-assigned defs are overridden
+pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
+
+pub fn handle_create_plan<S>(entry_def_id: S, plan: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (header_addr, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, plan)?;
+    construct_response(&base_address, header_addr, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_plan<S>(entry_def_id: S, address: PlanAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, revision, &entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_update_plan<S>(entry_def_id: S, plan: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let revision_hash = plan.get_revision_id().clone();
+    let (revision_id, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &revision_hash, plan)?;
+    construct_response(&identity_address, revision_id, &entry, get_link_fields(&identity_address)?)
+}
+
+/// Delete a Plan, provided that no processes or commitments are still planned within it.
+///
+/// Planned items must be moved or deleted by their authors before the Plan is removed, so that
+/// none are left referencing a Plan which no longer exists.
+///
+pub fn handle_delete_plan(address: RevisionHash) -> RecordAPIResult<bool> {
+    // load the record to ensure it is of the correct type
+    let (base_address, _entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&address)?;
+
+    let processes: Vec<ProcessAddress> = read_index!(plan(&base_address).processes)?;
+    let independent_demands: Vec<CommitmentAddress> = read_index!(plan(&base_address).independent_demands)?;
+    let non_process_commitments: Vec<CommitmentAddress> = read_index!(plan(&base_address).non_process_commitments)?;
+
+    let commitments = independent_demands.len() + non_process_commitments.len();
+
+    if processes.len() + commitments > 0 {
+        return Err(DataIntegrityError::UndeletableRecord(format!(
+            "Plan is still referenced by {} processes and {} commitments",
+            processes.len(), commitments,
+        )));
+    }
+
+    delete_record::<EntryData, RevisionHash>(&address)
+}
+
+//---------------- GENERATE FROM RECIPE ----------------
+
+/// Generate a new `Plan` for producing some quantity of a resource, by way of the recipes
+/// stored in the specification DNA which output it.
+///
+/// A planned `Process` is created for each `RecipeProcess` involved, along with `Commitments`
+/// for all of its recipe inputs & outputs. Quantities are scaled to the requested amount and
+/// each `Process` is scheduled to end when its outputs are needed, working backwards from `due`.
+///
+/// All recipes are read before anything is written, so that incomplete or cyclic recipes are
+/// refused without side-effects. Records in this cell are discarded along with the zome call if
+/// any later step fails; `Processes` already created in the observation cell are deleted.
+///
+pub fn handle_create_plan_from_recipe<S>(entry_def_id: S, request: CreateFromRecipeRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let output_flow = find_recipe_output(&request.resource_conforms_to)?
+        .ok_or(DataIntegrityError::InvalidRecord("no recipe produces the requested ResourceSpecification".to_string()))?;
+
+    let mut planned = vec![];
+    plan_recipe_output(&output_flow, &request.resource_quantity, &request.due, true, &mut vec![], &mut planned)?;
+
+    let (_revision_id, plan_address, _entry_resp): (_,_, EntryData) = create_record(&entry_def_id, CreateRequest {
+        name: request.name.to_owned(),
+        due: MaybeUndefined::Some(request.due.to_owned()),
+        created: MaybeUndefined::Some(sys_time()?.into()),
+        note: request.note.to_owned(),
+        refinement_of: MaybeUndefined::Undefined,
+    })?;
+
+    let context = RecipePlanContext {
+        plan: plan_address.to_owned(),
+        process_dna: ProcessDna(request.process_dna.to_owned()),
+        provider: request.provider.to_owned(),
+        receiver: request.receiver.to_owned(),
+    };
+
+    create_planned_processes(&context, &planned)?;
+
+    handle_get_plan(entry_def_id, plan_address)
+}
+
+/// Records shared by all `Processes` & `Commitments` generated for a single `Plan`
+struct RecipePlanContext {
+    plan: PlanAddress,
+    process_dna: ProcessDna,
+    provider: AgentAddress,
+    receiver: AgentAddress,
+}
+
+/// DNA in which to create planned `Processes`, for use as a remote call target
+struct ProcessDna(DnaHash);
+
+impl AsRef<DnaHash> for ProcessDna {
+    fn as_ref(&self) -> &DnaHash {
+        &self.0
+    }
+}
+
+/// A `Process` to be created for a `RecipeProcess`, along with the `Commitments` for its recipe flows
+struct PlannedProcess {
+    recipe_process: RecipeProcess,
+    has_beginning: DateTimeValue,
+    has_end: DateTimeValue,
+    outputs: Vec<PlannedCommitment>,
+    inputs: Vec<PlannedCommitment>,
+}
+
+/// A `Commitment` to be created for a recipe flow of a `PlannedProcess`
+struct PlannedCommitment {
+    flow: RecipeFlow,
+    recipe_resource: Option<RecipeResource>,
+    scale: f64,
+    is_independent_demand: bool,
+}
+
+/// Plan the `RecipeProcess` producing `output_flow`, such that it yields `quantity` of its output by `due`.
+///
+/// Any inputs which are themselves produced by a recipe are planned recursively, ending at the
+/// beginning of the dependant `Process`. `recipe_chain` tracks the `RecipeProcesses` currently
+/// being planned, in order to refuse cyclic recipes. Nothing is written; the `Processes` to
+/// create are appended to `planned`.
+///
+fn plan_recipe_output(
+    output_flow: &RecipeFlow,
+    quantity: &QuantityValue,
+    due: &DateTimeValue,
+    is_independent_demand: bool,
+    recipe_chain: &mut Vec<RecipeProcessAddress>,
+    planned: &mut Vec<PlannedProcess>,
+) -> RecordAPIResult<()> {
+    let recipe_process_address = match &output_flow.recipe_output_of {
+        Some(address) => address.to_owned(),
+        None => return Ok(()),
+    };
+    if recipe_chain.contains(&recipe_process_address) {
+        return Err(DataIntegrityError::InvalidRecord("recipe contains a cycle and cannot be planned".to_string()));
+    }
+    recipe_chain.push(recipe_process_address.to_owned());
+
+    let recipe_process = read_recipe_process(&recipe_process_address)?;
+    let scale = scale_factor(&output_flow.resource_quantity, quantity);
+
+    let has_end = due.to_owned();
+    let has_beginning = match &recipe_process.has_duration {
+        Some(duration) => has_end.seconds_before(duration.as_seconds()),
+        None => has_end.to_owned(),
+    };
+
+    let mut outputs = vec![];
+    for flow_address in recipe_process.recipe_outputs.iter() {
+        let flow = read_recipe_flow(flow_address)?;
+        let is_independent_demand = is_independent_demand && flow.id == output_flow.id;
+        outputs.push(plan_commitment(flow, scale, is_independent_demand)?);
+    }
+
+    let mut inputs = vec![];
+    let mut dependencies = vec![];
+    for flow_address in recipe_process.recipe_inputs.iter() {
+        let flow = read_recipe_flow(flow_address)?;
+
+        // plan the production of any input resources which have recipes of their own
+        if requires_input_resource(&flow.action) {
+            if let (Some(resource_conforms_to), Some(input_quantity)) = (&flow.resource_conforms_to, scale_quantity(&flow.resource_quantity, scale)) {
+                if let Some(input_recipe_output) = find_recipe_output(resource_conforms_to)? {
+                    dependencies.push((input_recipe_output, input_quantity));
+                }
+            }
+        }
+
+        inputs.push(plan_commitment(flow, scale, false)?);
+    }
+
+    planned.push(PlannedProcess { recipe_process, has_beginning: has_beginning.to_owned(), has_end, outputs, inputs });
+
+    for (input_recipe_output, input_quantity) in dependencies.iter() {
+        plan_recipe_output(input_recipe_output, input_quantity, &has_beginning, false, recipe_chain, planned)?;
+    }
+
+    recipe_chain.pop();
+    Ok(())
+}
+
+fn plan_commitment(flow: RecipeFlow, scale: f64, is_independent_demand: bool) -> RecordAPIResult<PlannedCommitment> {
+    // resources without a specification may still be described by the recipe's `RecipeResource`
+    let recipe_resource = match (&flow.resource_conforms_to, &flow.recipe_flow_resource) {
+        (None, Some(recipe_resource_address)) => Some(read_recipe_resource(recipe_resource_address)?),
+        _ => None,
+    };
+    Ok(PlannedCommitment { flow, recipe_resource, scale, is_independent_demand })
+}
+
+/// Write the `Processes` & `Commitments` determined by `plan_recipe_output`.
+///
+/// If any write fails, the `Processes` already created in the observation cell are deleted again.
+/// Any which could not be deleted are reported in the returned error.
+///
+fn create_planned_processes(context: &RecipePlanContext, planned: &[PlannedProcess]) -> RecordAPIResult<()> {
+    let mut created: Vec<RevisionHash> = vec![];
+    for planned_process in planned.iter() {
+        if let Err(e) = create_planned_process(context, planned_process, &mut created) {
+            let not_rolled_back: Vec<String> = created.iter().rev()
+                .filter_map(|revision_id| {
+                    let result: OtherCellResult<bool> = call_zome_method(
+                        &context.process_dna,
+                        &PROCESS_DELETE_API_METHOD,
+                        DeleteProcessParams { address: revision_id.to_owned() },
+                    );
+                    result.err().map(|err| format!("{:?} ({})", revision_id, err))
+                })
+                .collect();
+            if not_rolled_back.is_empty() {
+                return Err(e);
+            }
+            return Err(DataIntegrityError::IncompleteRollback(Box::new(e), not_rolled_back.join(", ")));
+        }
+    }
+    Ok(())
+}
+
+/// Create a planned `Process` in the observation cell, followed by its `Commitments`.
+/// The revision of the `Process` is appended to `created` as soon as it is written.
+///
+fn create_planned_process(context: &RecipePlanContext, planned: &PlannedProcess, created: &mut Vec<RevisionHash>) -> RecordAPIResult<()> {
+    let recipe_process = &planned.recipe_process;
+
+    let process_resp: ProcessResponse = call_zome_method(
+        &context.process_dna,
+        &PROCESS_CREATE_API_METHOD,
+        CreateProcessParams { process: ProcessCreateRequest {
+            name: recipe_process.name.to_owned(),
+            has_beginning: MaybeUndefined::Some(planned.has_beginning.to_owned()),
+            has_end: MaybeUndefined::Some(planned.has_end.to_owned()),
+            before: MaybeUndefined::Undefined,
+            after: MaybeUndefined::Undefined,
+            classified_as: recipe_process.process_classified_as.to_owned().into(),
+            based_on: recipe_process.process_conforms_to.to_owned().into(),
+            planned_within: MaybeUndefined::Some(context.plan.to_owned()),
+            finished: MaybeUndefined::Some(false),
+            in_scope_of: MaybeUndefined::Undefined,
+            idempotency_key: MaybeUndefined::Undefined,
+            note: recipe_process.note.to_owned().into(),
+        } },
+    )?;
+    created.push(process_resp.process.revision_id.to_owned());
+    let process_address = process_resp.process.id;
+
+    // the observation cell must not call back into this one while it is being written to,
+    // so `Plan.processes` is indexed here instead
+    let _: RemoteEntryLinkResponse = call_local_zome_method(
+        read_plan_index_zome,
+        PLAN_PROCESSES_INDEXING_API_METHOD,
+        RemoteEntryLinkRequest::new(&process_address, &[context.plan.to_owned()], &[]),
+    )?;
+
+    for commitment in planned.outputs.iter() {
+        create_planned_commitment(context, commitment, None, Some(&process_address), &planned.has_end)?;
+    }
+    for commitment in planned.inputs.iter() {
+        create_planned_commitment(context, commitment, Some(&process_address), None, &planned.has_beginning)?;
+    }
+
+    Ok(())
+}
+
+/// Create a `Commitment` in the local commitment zome for a recipe flow of a planned `Process`
+fn create_planned_commitment(
+    context: &RecipePlanContext,
+    planned: &PlannedCommitment,
+    input_of: Option<&ProcessAddress>,
+    output_of: Option<&ProcessAddress>,
+    due: &DateTimeValue,
+) -> RecordAPIResult<CommitmentAddress> {
+    let flow = &planned.flow;
+    let (resource_conforms_to, resource_classified_as) = match &planned.recipe_resource {
+        Some(recipe_resource) => (recipe_resource.resource_conforms_to.to_owned(), recipe_resource.resource_classified_as.to_owned()),
+        None => (flow.resource_conforms_to.to_owned(), None),
+    };
+    let independent_demand_of = if planned.is_independent_demand { Some(context.plan.to_owned()) } else { None };
+
+    let resp: CommitmentResponse = call_local_zome_method(
+        read_commitment_zome,
+        COMMITMENT_CREATE_API_METHOD,
+        CreateCommitmentParams { commitment: CommitmentCreateRequest {
+            action: flow.action.to_owned(),
+            note: flow.note.to_owned().into(),
+            input_of: input_of.cloned().into(),
+            output_of: output_of.cloned().into(),
+            provider: context.provider.to_owned(),
+            receiver: context.receiver.to_owned(),
+            resource_inventoried_as: MaybeUndefined::Undefined,
+            resource_classified_as: resource_classified_as.into(),
+            resource_conforms_to: resource_conforms_to.into(),
+            resource_quantity: scale_quantity(&flow.resource_quantity, planned.scale).into(),
+            effort_quantity: scale_quantity(&flow.effort_quantity, planned.scale).into(),
+            has_beginning: MaybeUndefined::Undefined,
+            has_end: MaybeUndefined::Undefined,
+            has_point_in_time: MaybeUndefined::Undefined,
+            due: MaybeUndefined::Some(due.to_owned()),
+            at_location: MaybeUndefined::Undefined,
+            plan: MaybeUndefined::Some(context.plan.to_owned()),
+            agreed_in: MaybeUndefined::Undefined,
+            clause_of: MaybeUndefined::Undefined,
+            independent_demand_of: independent_demand_of.into(),
+            finished: MaybeUndefined::Some(false),
+            in_scope_of: MaybeUndefined::Undefined,
+            idempotency_key: MaybeUndefined::Undefined,
+        } },
+    )?;
+
+    Ok(resp.commitment.id)
+}
+
+/// Locate a `RecipeFlow` which outputs the given `ResourceSpecification` from some `RecipeProcess`
+///
+/// :TODO: allow choosing between alternative recipes; the first one found is presently used
+///
+fn find_recipe_output(resource_conforms_to: &ResourceSpecificationAddress) -> RecordAPIResult<Option<RecipeFlow>> {
+    let resp: RecipeFlowQueryResults = call_zome_method(
+        resource_conforms_to,
+        &RECIPE_FLOW_QUERY_API_METHOD,
+        RecipeFlowQuery { params: RecipeFlowQueryParams {
+            recipe_input_of: None,
+            recipe_output_of: None,
+            recipe_flow_resource: None,
+            recipe_clause_of: None,
+            resource_conforms_to: Some(resource_conforms_to.to_owned()),
+        } },
+    )?;
+
+    Ok(resp.results.into_iter()
+        .map(|r| r.recipe_flow)
+        .find(|flow| flow.recipe_output_of.is_some()))
+}
+
+fn read_recipe_flow(address: &RecipeFlowAddress) -> RecordAPIResult<RecipeFlow> {
+    let resp: RecipeFlowResponse = call_zome_method(
+        address,
+        &RECIPE_FLOW_READ_API_METHOD,
+        ByAddress { address: address.to_owned() },
+    )?;
+    Ok(resp.recipe_flow)
+}
+
+fn read_recipe_process(address: &RecipeProcessAddress) -> RecordAPIResult<RecipeProcess> {
+    let resp: RecipeProcessResponse = call_zome_method(
+        address,
+        &RECIPE_PROCESS_READ_API_METHOD,
+        ByAddress { address: address.to_owned() },
+    )?;
+    Ok(resp.recipe_process)
+}
+
+fn read_recipe_resource(address: &RecipeResourceAddress) -> RecordAPIResult<RecipeResource> {
+    let resp: RecipeResourceResponse = call_zome_method(
+        address,
+        &RECIPE_RESOURCE_READ_API_METHOD,
+        ByAddress { address: address.to_owned() },
+    )?;
+    Ok(resp.recipe_resource)
+}
+
+/// Ratio by which recipe quantities must be multiplied in order to yield `required` of a recipe output
+///
+/// :TODO: unit conversion; requested quantities are presently assumed to be in the units of the recipe
+///
+fn scale_factor(recipe_output: &Option<QuantityValue>, required: &QuantityValue) -> f64 {
+    match recipe_output {
+        Some(output) if output.get_numerical_value() != 0.0 => required.get_numerical_value() / output.get_numerical_value(),
+        _ => required.get_numerical_value(),
+    }
+}
+
+/// Whether an input flow of the given action needs its resource to exist before the `Process`
+/// begins, ie. whether it should be planned for: `consume`, `use`, `cite` & `combine`.
+///
+/// Inputs which are paired with an output (eg. `accept`) return the same resource, and `work`
+/// refers to skills rather than resources.
+///
+fn requires_input_resource(action_id: &ActionId) -> bool {
+    match get_builtin_action(action_id.as_ref()) {
+        Some(action) => action.input_output == ProcessType::Input
+            && action.pairs_with == "notApplicable"
+            && !is_work(action_id),
+        None => false,
+    }
+}
+
+fn scale_quantity(quantity: &Option<QuantityValue>, scale: f64) -> Option<QuantityValue> {
+    quantity.as_ref().map(|q| QuantityValue::new(q.get_numerical_value() * scale, q.get_unit()))
+}
+
+/// Parameters for the Process zome API creating a new Process
+#[derive(Debug, Serialize)]
+struct CreateProcessParams {
+    process: ProcessCreateRequest,
+}
+
+/// Parameters for the Process zome API deleting a Process
+#[derive(Debug, Serialize)]
+struct DeleteProcessParams {
+    address: RevisionHash,
+}
+
+/// Parameters for the Commitment zome API creating a new Commitment
+#[derive(Debug, Serialize)]
+struct CreateCommitmentParams {
+    commitment: CommitmentCreateRequest,
+}
+
+/// Parameters for the RecipeFlow index zome query API
+#[derive(Debug, Serialize)]
+struct RecipeFlowQuery {
+    params: RecipeFlowQueryParams,
+}
+
+/// Results of the RecipeFlow index zome query API
+#[derive(Debug, Deserialize)]
+struct RecipeFlowQueryResults {
+    #[serde(default)]
+    results: Vec<RecipeFlowResponse>,
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &PlanAddress, revision: RevisionHash, e: &EntryData, (
+        processes,
+        independent_demands,
+        non_process_commitments,
+    ): (
+        Vec<ProcessAddress>,
+        Vec<CommitmentAddress>,
+        Vec<CommitmentAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        plan: Response {
+            id: address.to_owned(),
+            revision_id: revision.to_owned(),
+            name: e.name.to_owned(),
+            due: e.due.to_owned(),
+            created: e.created.to_owned(),
+            note: e.note.to_owned(),
+            refinement_of: e.refinement_of.to_owned(),
+            processes: processes.to_owned(),
+            independent_demands: independent_demands.to_owned(),
+            non_process_commitments: non_process_commitments.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_plan_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.plan.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_commitment_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.plan.commitment_zome
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &PlanAddress) -> RecordAPIResult<(
+    Vec<ProcessAddress>,
+    Vec<CommitmentAddress>,
+    Vec<CommitmentAddress>,
+)> {
+    Ok((
+        read_index!(plan(base_address).processes)?,
+        read_index!(plan(base_address).independent_demands)?,
+        read_index!(plan(base_address).non_process_commitments)?,
+    ))
+}
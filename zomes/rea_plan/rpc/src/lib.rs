@@ -0,0 +1,152 @@
+/**
+ * Holo-REA plan zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package Holo-REA
+ */
+use holochain_serialized_bytes::prelude::*;
+
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_measurement::QuantityValue;
+pub use vf_attributes_hdk::{
+    RevisionHash,
+    DnaHash,
+    PlanAddress,
+    ScenarioAddress,
+    ProcessAddress,
+    CommitmentAddress,
+    AgentAddress,
+    ResourceSpecificationAddress,
+    DateTimeValue,
+};
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: PlanAddress,
+    pub revision_id: RevisionHash,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTimeValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTimeValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refinement_of: Option<ScenarioAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub processes: Vec<ProcessAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub independent_demands: Vec<CommitmentAddress>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub non_process_commitments: Vec<CommitmentAddress>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub plan: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub due: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub created: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub refinement_of: MaybeUndefined<ScenarioAddress>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+/// I/O struct describing a `Plan` to be generated from the recipes which produce a resource
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFromRecipeRequest {
+    pub name: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+    /// the `ResourceSpecification` output by the recipe to be planned
+    pub resource_conforms_to: ResourceSpecificationAddress,
+    /// the quantity of the resource required, which recipe quantities are scaled to
+    pub resource_quantity: QuantityValue,
+    /// when the resource is required, which all `Processes` are scheduled backwards from
+    pub due: DateTimeValue,
+    /// the DNA in which planned `Processes` are to be created
+    pub process_dna: DnaHash,
+    pub provider: AgentAddress,
+    pub receiver: AgentAddress,
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: RevisionHash,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub name: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub due: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub created: MaybeUndefined<DateTimeValue>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub note: MaybeUndefined<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub refinement_of: MaybeUndefined<ScenarioAddress>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&self) -> RevisionHash {
+        self.revision_id.to_owned().into()
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub processes: Option<ProcessAddress>,
+    pub independent_demands: Option<CommitmentAddress>,
+    pub non_process_commitments: Option<CommitmentAddress>,
+}
@@ -0,0 +1,85 @@
+/**
+ * Holo-REA plan zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    generate_record_entry,
+    record_interface::{Updateable},
+};
+
+use vf_attributes_hdk::{
+    DateTimeValue,
+    ScenarioAddress,
+};
+
+use hc_zome_rea_plan_rpc::{ CreateRequest, UpdateRequest };
+
+pub use vf_attributes_hdk::PlanAddress;
+pub use hc_zome_rea_plan_storage_consts::PLAN_ENTRY_TYPE;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub plan: PlanZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct PlanZomeConfig {
+    pub index_zome: String,
+    // zome ID of a ValueFlows `Commitment`-compatible zome, used to create the commitments of plans generated from recipes.
+    pub commitment_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct EntryData {
+    pub name: String,
+    pub due: Option<DateTimeValue>,
+    pub created: Option<DateTimeValue>,
+    pub note: Option<String>,
+    pub refinement_of: Option<ScenarioAddress>,
+}
+
+generate_record_entry!(EntryData, PlanAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            name: e.name.into(),
+            due: e.due.into(),
+            created: e.created.into(),
+            note: e.note.into(),
+            refinement_of: e.refinement_of.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            due: if !e.due.is_some() { self.due.to_owned() } else { e.due.to_owned().into() },
+            created: if !e.created.is_some() { self.created.to_owned() } else { e.created.to_owned().into() },
+            note: if !e.note.is_some() { self.note.to_owned() } else { e.note.to_owned().into() },
+            refinement_of: if !e.refinement_of.is_some() { self.refinement_of.to_owned() } else { e.refinement_of.to_owned().into() },
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const PLAN_ENTRY_TYPE: &str = "vf_plan";
+
+pub const PLAN_PROCESSES_LINK_TAG: &str = "processes";
+pub const PLAN_INDEPENDENT_DEMANDS_LINK_TAG: &str = "independent_demands";
+pub const PLAN_NON_PROCESS_COMMITMENTS_LINK_TAG: &str = "non_process_commitments";
+
+pub const PLAN_PROCESSES_READ_API_METHOD: &str = "_internal_read_plan_processes";
+pub const PLAN_INDEPENDENT_DEMANDS_READ_API_METHOD: &str = "_internal_read_plan_independent_demands";
+pub const PLAN_NON_PROCESS_COMMITMENTS_READ_API_METHOD: &str = "_internal_read_plan_non_process_commitments";
+pub const PLAN_PROCESSES_INDEXING_API_METHOD: &str = "index_plan_processes";
+
+pub const COMMITMENT_CREATE_API_METHOD: &str = "create_commitment";
+pub const PROCESS_CREATE_API_METHOD: &str = "create_planned_process";
+pub const PROCESS_DELETE_API_METHOD: &str = "delete_planned_process";
+pub const RECIPE_FLOW_QUERY_API_METHOD: &str = "query_recipe_flows";
+pub const RECIPE_FLOW_READ_API_METHOD: &str = "read_recipe_flow";
+pub const RECIPE_PROCESS_READ_API_METHOD: &str = "read_recipe_process";
+pub const RECIPE_RESOURCE_READ_API_METHOD: &str = "read_recipe_resource";
@@ -0,0 +1,14 @@
+/**
+ * Plan query indexes for planning DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_plan_rpc::*;
+
+#[index_zome]
+struct Plan {
+    processes: Remote<process, planned_within>,
+    independent_demands: Local<commitment, independent_demand_of>,
+    non_process_commitments: Local<commitment, planned_within>,
+}
@@ -7,10 +7,13 @@
  * @package Holo-REA
  */
 use paste::paste;
+use hdk::prelude::*;
 use hdk_records::{
-    RecordAPIResult,
+    RecordAPIResult, DataIntegrityError, MaybeUndefined,
     records::{
         create_record,
+        read_existing_record,
+        create_idempotency_key_index,
         read_record_entry,
         read_record_entry_by_header,
         update_record,
@@ -18,14 +21,58 @@ use hdk_records::{
     },
 };
 use hdk_semantic_indexes_client_lib::*;
+use vf_actions::{ Action, get_builtin_action, is_work };
 
+use hc_zome_rea_process_storage_consts::*;
 use hc_zome_rea_process_storage::*;
 use hc_zome_rea_process_rpc::*;
+use hc_zome_rea_economic_event_storage_consts::EVENT_ENTRY_TYPE;
+use hc_zome_rea_economic_event_storage::{
+    EntryData as EventData,
+    EntryStorage as EventStorage,
+};
+use hc_zome_rea_fulfillment_storage_consts::FULFILLMENT_ENTRY_TYPE;
+use hc_zome_rea_fulfillment_storage::{
+    EntryData as FulfillmentData,
+    EntryStorage as FulfillmentStorage,
+};
+use hc_zome_rea_fulfillment_rpc::FulfillmentAddress;
+
+// :SHONK: needed to re-export for zome `entry_defs()` where macro-assigned defs are overridden
+pub use hdk_records::CAP_STORAGE_ENTRY_DEF_ID;
 
 pub fn handle_create_process<S>(entry_def_id: S, process: CreateRequest) -> RecordAPIResult<ResponseData>
     where S: AsRef<str>
 {
+    create_process(entry_def_id, process, false)
+}
+
+/// Create a `Process` on behalf of the `Plan` it is planned within.
+///
+/// For use by planning cells generating processes mid-way through a write of their own. These
+/// index `Plan.processes` themselves, rather than accept a call back into the cell being written to.
+///
+pub fn handle_create_planned_process<S>(entry_def_id: S, process: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    create_process(entry_def_id, process, true)
+}
+
+fn create_process<S>(entry_def_id: S, process: CreateRequest, plan_indexed_by_caller: bool) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    // if a record has already been created with this idempotency key (eg. a retried request), return it without re-indexing
+    let idempotency_key: Option<String> = process.idempotency_key.to_owned().into();
+    if let Some((header_addr, base_address, entry_resp)) = read_existing_record::<EntryData, EntryStorage, ProcessAddress, _>(&entry_def_id, &idempotency_key)? {
+        return construct_response(&base_address, &header_addr, &entry_resp, get_link_fields(&base_address)?);
+    }
+
     let (header_addr, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, process)?;
+    create_idempotency_key_index(&entry_def_id, &idempotency_key, &base_address)?;
+
+    // handle link fields
+    update_plan_index(&base_address, &entry_resp.planned_within, &None, plan_indexed_by_caller)?;
+
     construct_response(&base_address, &header_addr, &entry_resp, get_link_fields(&base_address)?)
 }
 
@@ -40,18 +87,317 @@ pub fn handle_update_process<S>(entry_def_id: S, process: UpdateRequest) -> Reco
     where S: AsRef<str>
 {
     let address = process.get_revision_id().clone();
-    let (revision_id, identity_address, entry, _prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &address, process)?;
+
+    // paired flows must be complete before a Process can be marked as finished
+    if let MaybeUndefined::Some(true) = process.finished {
+        let (base_address, prev_entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&address)?;
+        if !prev_entry.finished {
+            let warnings = read_unpaired_flows(EVENT_ENTRY_TYPE, &base_address)?;
+            if !warnings.is_empty() {
+                return Err(DataIntegrityError::InvalidRecord(format!(
+                    "Process cannot be finished with unpaired flows: {}", warnings.join("; "),
+                )));
+            }
+        }
+    }
+
+    let (revision_id, identity_address, entry, prev_entry): (_,_, EntryData, EntryData) = update_record(&entry_def_id, &address, process)?;
+
+    // handle link fields
+    if entry.planned_within != prev_entry.planned_within {
+        update_plan_index(&identity_address, &entry.planned_within, &prev_entry.planned_within, false)?;
+    }
+
     construct_response(&identity_address, &revision_id, &entry, get_link_fields(&identity_address)?)
 }
 
+/// Delete a Process, provided that no events, commitments or intents still reference it.
+///
+/// Events are immutable records of what happened and so cannot be unlinked from a Process;
+/// planned items must be moved or deleted by their authors before the Process is removed.
+///
 pub fn handle_delete_process<S>(_entry_def_id: S, revision_id: RevisionHash) -> RecordAPIResult<bool>
 {
     // load the record to ensure it is of the correct type
-    let (_base_address, _entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    let inputs: Vec<EconomicEventAddress> = read_index!(process(&base_address).inputs)?;
+    let outputs: Vec<EconomicEventAddress> = read_index!(process(&base_address).outputs)?;
+    let committed_inputs: Vec<CommitmentAddress> = read_index!(process(&base_address).committed_inputs)?;
+    let committed_outputs: Vec<CommitmentAddress> = read_index!(process(&base_address).committed_outputs)?;
+    let intended_inputs: Vec<IntentAddress> = read_index!(process(&base_address).intended_inputs)?;
+    let intended_outputs: Vec<IntentAddress> = read_index!(process(&base_address).intended_outputs)?;
+
+    let events = inputs.len() + outputs.len();
+    let commitments = committed_inputs.len() + committed_outputs.len();
+    let intents = intended_inputs.len() + intended_outputs.len();
+
+    if events + commitments + intents > 0 {
+        return Err(DataIntegrityError::UndeletableRecord(format!(
+            "Process is still referenced by {} economic events, {} commitments and {} intents",
+            events, commitments, intents,
+        )));
+    }
 
+    // handle link fields
+    update_plan_index(&base_address, &None, &entry.planned_within, false)?;
+
+    // delete entry last, as it must be present in order for links to be removed
     delete_record::<EntryStorage, _>(&revision_id)
 }
 
+/// Delete a `Process` created by `handle_create_planned_process`, where the `Plan` it was created for
+/// failed to be written.
+///
+/// Commitments & intents indexed against the Process were discarded along with the Plan, and so do not
+/// prevent its removal. Events are still checked, since they may have been recorded in the meantime.
+///
+pub fn handle_delete_planned_process<S>(_entry_def_id: S, revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    // load the record to ensure it is of the correct type
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    let inputs: Vec<EconomicEventAddress> = read_index!(process(&base_address).inputs)?;
+    let outputs: Vec<EconomicEventAddress> = read_index!(process(&base_address).outputs)?;
+
+    let events = inputs.len() + outputs.len();
+    if events > 0 {
+        return Err(DataIntegrityError::UndeletableRecord(format!(
+            "Process is still referenced by {} economic events", events,
+        )));
+    }
+
+    // handle link fields
+    update_plan_index(&base_address, &None, &entry.planned_within, true)?;
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Maintain `Process.planned_within` and, unless `plan_indexed_by_caller`, its inverse `Plan.processes`
+/// in the planning DNA.
+///
+/// Planning cells writing processes on behalf of a `Plan` cannot be called back into while their own
+/// write is in progress, and so update `Plan.processes` themselves.
+///
+fn update_plan_index(
+    process: &ProcessAddress,
+    planned_within: &Option<PlanAddress>,
+    prev_planned_within: &Option<PlanAddress>,
+    plan_indexed_by_caller: bool,
+) -> RecordAPIResult<()> {
+    let new_value = match planned_within { Some(val) => vec![val.to_owned()], None => vec![] };
+    let prev_value = match prev_planned_within { Some(val) => vec![val.to_owned()], None => vec![] };
+    if new_value.is_empty() && prev_value.is_empty() {
+        return Ok(());
+    }
+
+    if plan_indexed_by_caller {
+        update_index!(Self(
+            process(process)
+                .planned_within(new_value.as_slice())
+                .not(prev_value.as_slice())
+        ))?;
+    } else {
+        update_index!(Remote(
+            process
+                .planned_within(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            plan.processes(process)
+        ))?;
+    }
+    Ok(())
+}
+
+/// Maintain the indexes derived from the flows into & out of a Process: the resources & resource
+/// specifications it exchanges, which connect it to its `next_processes` & `previous_processes`;
+/// and the agents performing `work` within it.
+///
+/// Called by the zomes managing events, commitments & intents whenever a flow of the Process is
+/// created, changed or removed. Observed flows are indexed individually as they change, using
+/// only data held in this DNA. Changes to planned flows recompute the indexes from all flows of
+/// the Process, so repeated or out-of-order calls converge on the same result.
+///
+/// Flows can only be indexed against a Process which exists.
+///
+pub fn handle_index_process_flows<S>(entry_def_id: S, params: IndexFlowsParams) -> RecordAPIResult<()>
+    where S: AsRef<str>
+{
+    let process = params.address;
+    read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, process.as_ref())?;
+
+    match params.update {
+        FlowsUpdate::EventAdded { event, is_input } => index_event_added(&process, &event, is_input),
+        FlowsUpdate::EventRemoved { event, is_input } => index_event_removed(&process, &event, is_input),
+        FlowsUpdate::PlannedFlowsChanged { inputs, outputs } => reindex_process_flows(&process, &inputs, &outputs),
+    }
+}
+
+fn index_event_added(process: &ProcessAddress, event: &ObservedFlow, is_input: bool) -> RecordAPIResult<()> {
+    let mut flows = ProcessFlows::default();
+    flows.add_observed_flow(event, is_input);
+
+    let indexed = read_indexed_flows(process, is_input)?;
+    update_flow_indexes(process, is_input, &flows.without(&indexed), &ProcessFlows::default())
+}
+
+fn index_event_removed(process: &ProcessAddress, event: &ObservedFlow, is_input: bool) -> RecordAPIResult<()> {
+    // :TODO: the subjects of planned flows are only known when the planning DNA sends them. Rather than
+    // reading them from there, removal is left to the next change in planned flows of the Process.
+    let (commitments, intents): (Vec<CommitmentAddress>, Vec<IntentAddress>) = if is_input {
+        (read_index!(process(process).committed_inputs)?, read_index!(process(process).intended_inputs)?)
+    } else {
+        (read_index!(process(process).committed_outputs)?, read_index!(process(process).intended_outputs)?)
+    };
+    if !commitments.is_empty() || !intents.is_empty() {
+        return Ok(());
+    }
+
+    let mut removed = ProcessFlows::default();
+    removed.add_observed_flow(event, is_input);
+
+    let events: Vec<EconomicEventAddress> = if is_input {
+        read_index!(process(process).inputs)?
+    } else {
+        read_index!(process(process).outputs)?
+    };
+    let mut remaining = ProcessFlows::default();
+    for (address, entry) in read_process_events(EVENT_ENTRY_TYPE, events)? {
+        if address != event.address {
+            remaining.add_observed_flow(&observed_flow(&address, &entry), is_input);
+        }
+    }
+
+    update_flow_indexes(process, is_input, &ProcessFlows::default(), &removed.without(&remaining))
+}
+
+fn reindex_process_flows(process: &ProcessAddress, planned_inputs: &[PlannedFlow], planned_outputs: &[PlannedFlow]) -> RecordAPIResult<()> {
+    let inputs = read_flows(&read_index!(process(process).inputs)?, planned_inputs, true)?;
+    let outputs = read_flows(&read_index!(process(process).outputs)?, planned_outputs, false)?;
+
+    let indexed = read_indexed_flows(process, true)?;
+    update_flow_indexes(process, true, &inputs.without(&indexed), &indexed.without(&inputs))?;
+    let indexed = read_indexed_flows(process, false)?;
+    update_flow_indexes(process, false, &outputs.without(&indexed), &indexed.without(&outputs))
+}
+
+/// Read the resources, resource specifications & working agents currently indexed on one side of a Process
+fn read_indexed_flows(process: &ProcessAddress, is_input: bool) -> RecordAPIResult<ProcessFlows> {
+    Ok(if is_input {
+        ProcessFlows {
+            resources: read_index!(process(process).input_resources)?,
+            specifications: read_index!(process(process).input_specifications)?,
+            working_agents: read_index!(process(process).working_agents)?,
+        }
+    } else {
+        ProcessFlows {
+            resources: read_index!(process(process).output_resources)?,
+            specifications: read_index!(process(process).output_specifications)?,
+            working_agents: vec![],
+        }
+    })
+}
+
+fn update_flow_indexes(process: &ProcessAddress, is_input: bool, added: &ProcessFlows, removed: &ProcessFlows) -> RecordAPIResult<()> {
+    if is_input {
+        if !added.resources.is_empty() || !removed.resources.is_empty() {
+            update_index!(Local(process.input_resources(added.resources.as_slice()).not(removed.resources.as_slice()), economic_resource.flows_into(process)))?;
+        }
+        if !added.specifications.is_empty() || !removed.specifications.is_empty() {
+            update_index!(Local(process.input_specifications(added.specifications.as_slice()).not(removed.specifications.as_slice()), resource_specification.flows_into(process)))?;
+        }
+        if !added.working_agents.is_empty() || !removed.working_agents.is_empty() {
+            update_index!(Self(process(process).working_agents(added.working_agents.as_slice()).not(removed.working_agents.as_slice())))?;
+        }
+    } else {
+        if !added.resources.is_empty() || !removed.resources.is_empty() {
+            update_index!(Local(process.output_resources(added.resources.as_slice()).not(removed.resources.as_slice()), economic_resource.flows_out_of(process)))?;
+        }
+        if !added.specifications.is_empty() || !removed.specifications.is_empty() {
+            update_index!(Local(process.output_specifications(added.specifications.as_slice()).not(removed.specifications.as_slice()), resource_specification.flows_out_of(process)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that every paired input flow of a finished Process (eg. `accept`, `pickup`)
+/// has a corresponding output flow (eg. `modify`, `dropoff`) on the same resource.
+///
+/// Processes cannot be marked as finished while flows are unmatched (@see `handle_update_process`),
+/// but flows recorded after that point are reported here as warnings.
+///
+pub fn handle_validate_process_flows<S>(entry_def_id: S, event_entry_def_id: S, address: ProcessAddress) -> RecordAPIResult<FlowValidationResponse>
+    where S: AsRef<str>
+{
+    let (_revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+
+    if !entry.finished {
+        return Ok(FlowValidationResponse { process: base_address, finished: false, warnings: vec![] });
+    }
+
+    let warnings = read_unpaired_flows(&event_entry_def_id, &base_address)?;
+
+    Ok(FlowValidationResponse { process: base_address, finished: true, warnings })
+}
+
+/// Describe each paired input flow of a Process which has no matching output flow
+fn read_unpaired_flows<S>(event_entry_def_id: S, process: &ProcessAddress) -> RecordAPIResult<Vec<String>>
+    where S: AsRef<str>
+{
+    let inputs = read_process_events(&event_entry_def_id, read_index!(process(process).inputs)?)?;
+    let outputs = read_process_events(&event_entry_def_id, read_index!(process(process).outputs)?)?;
+    let mut matched_outputs: Vec<bool> = outputs.iter().map(|_| false).collect();
+    let mut warnings: Vec<String> = vec![];
+
+    for (input_id, input) in inputs.iter() {
+        let action = match get_builtin_action(input.action.as_ref()) {
+            Some(action) => action,
+            None => continue,
+        };
+        if action.pairs_with == "notApplicable" {
+            continue;
+        }
+
+        let pair = outputs.iter().enumerate()
+            .find(|(idx, (_, output))| !matched_outputs[*idx] && flows_are_paired(&action, input, output));
+
+        match pair {
+            Some((idx, _)) => { matched_outputs[idx] = true; },
+            None => warnings.push(format!(
+                "'{}' input {:?} has no matching '{}' output on the same resource",
+                action.id, input_id, action.pairs_with,
+            )),
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn read_process_events<S>(event_entry_def_id: S, events: Vec<EconomicEventAddress>) -> RecordAPIResult<Vec<(EconomicEventAddress, EventData)>>
+    where S: AsRef<str>
+{
+    events.iter()
+        .map(|event_id| {
+            let (_, _, event) = read_record_entry::<EventData, EventStorage, _,_>(&event_entry_def_id, event_id.as_ref())?;
+            Ok((event_id.to_owned(), event))
+        })
+        .collect()
+}
+
+/// Determines whether an output event completes the paired action of an input event
+fn flows_are_paired(input_action: &Action, input: &EventData, output: &EventData) -> bool {
+    let output_action_id: &String = output.action.as_ref();
+    let pairs_action = *output_action_id == input_action.pairs_with
+        || get_builtin_action(output_action_id).map_or(false, |a| a.pairs_with == input_action.id);
+
+    pairs_action && match (&input.resource_inventoried_as, &output.resource_inventoried_as) {
+        (Some(input_resource), Some(output_resource)) => input_resource == output_resource,
+        (None, None) => input.resource_conforms_to == output.resource_conforms_to
+            && input.resource_classified_as == output.resource_classified_as,
+        _ => false,
+    }
+}
+
 /// Create response from input DHT primitives
 fn construct_response<'a>(
     address: &ProcessAddress, revision_id: &RevisionHash, e: &EntryData, (
@@ -88,7 +434,10 @@ fn construct_response<'a>(
             note: e.note.to_owned(),
             in_scope_of: e.in_scope_of.to_owned(),
             finished: e.finished.to_owned(),
-            deletable: true,    // :TODO:
+            // @see handle_delete_process
+            deletable: inputs.is_empty() && outputs.is_empty()
+                && committed_inputs.is_empty() && committed_outputs.is_empty()
+                && intended_inputs.is_empty() && intended_outputs.is_empty(),
 
             // link fields
             inputs: inputs.to_owned(),
@@ -114,6 +463,21 @@ fn read_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
     Some(conf.process.index_zome)
 }
 
+/// Properties accessor for zome config.
+fn read_economic_event_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.process.economic_event_index_zome
+}
+
+/// Properties accessor for zome config.
+fn read_economic_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.process.economic_resource_index_zome
+}
+
+/// Properties accessor for zome config.
+fn read_resource_specification_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.process.resource_specification_index_zome
+}
+
 // @see construct_response
 fn get_link_fields(process: &ProcessAddress) -> RecordAPIResult<(
     Vec<EconomicEventAddress>,
@@ -129,18 +493,186 @@ fn get_link_fields(process: &ProcessAddress) -> RecordAPIResult<(
     Vec<EconomicEventAddress>,
     Vec<EconomicEventAddress>,
 )> {
+    let inputs = read_index!(process(process).inputs)?;
+    let outputs = read_index!(process(process).outputs)?;
+    let committed_inputs = read_index!(process(process).committed_inputs)?;
+    let committed_outputs = read_index!(process(process).committed_outputs)?;
+    let intended_inputs = read_index!(process(process).intended_inputs)?;
+    let intended_outputs = read_index!(process(process).intended_outputs)?;
+
+    let (next_processes, previous_processes) = get_adjacent_processes(process)?;
+
     Ok((
-        read_index!(process(process).inputs)?,
-        read_index!(process(process).outputs)?,
-        vec![],  // :TODO: unplanned_economic_events
-        read_index!(process(process).committed_inputs)?,
-        read_index!(process(process).committed_outputs)?,
-        read_index!(process(process).intended_inputs)?,
-        read_index!(process(process).intended_outputs)?,
-        vec![], // :TODO: next_processes
-        vec![], // :TODO: previous_processes
-        vec![], // :TODO: working_agents
-        vec![], // :TODO: trace
-        vec![], // :TODO: track
+        inputs.to_owned(),
+        outputs.to_owned(),
+        read_unplanned_events(
+            &[inputs.as_slice(), outputs.as_slice()].concat(),
+            &[committed_inputs.as_slice(), committed_outputs.as_slice()].concat(),
+        )?,
+        committed_inputs,
+        committed_outputs,
+        intended_inputs,
+        intended_outputs,
+        next_processes,
+        previous_processes,
+        read_index!(process(process).working_agents)?,
+        inputs,     // trace: one step back from a process is its inputs
+        outputs,    // track: one step forward from a process is its outputs
     ))
 }
+
+/// Determine which observed inputs & outputs of a Process were not planned,
+/// ie. do not fulfill any of the Commitments made as inputs or outputs of the same Process.
+///
+fn read_unplanned_events(
+    events: &[EconomicEventAddress],
+    commitments: &[CommitmentAddress],
+) -> RecordAPIResult<Vec<EconomicEventAddress>> {
+    let mut unplanned = vec![];
+
+    for event_address in events {
+        let fulfillments: Vec<FulfillmentAddress> = read_index!(economic_event(event_address).fulfills)?;
+        let mut planned = false;
+
+        for fulfillment_address in fulfillments.iter() {
+            let (_, _, fulfillment) = read_record_entry::<FulfillmentData, FulfillmentStorage, _,_>(&FULFILLMENT_ENTRY_TYPE, fulfillment_address.as_ref())?;
+            if commitments.contains(&fulfillment.fulfills) {
+                planned = true;
+                break;
+            }
+        }
+
+        if !planned {
+            unplanned.push(event_address.to_owned());
+        }
+    }
+
+    Ok(unplanned)
+}
+
+//---------------- PROCESS GRAPH ----------------
+
+/// The resources, resource specifications & working agents concerned by the observed and
+/// planned flows on one side of a Process.
+///
+#[derive(Default)]
+struct ProcessFlows {
+    resources: Vec<EconomicResourceAddress>,
+    specifications: Vec<ResourceSpecificationAddress>,
+    working_agents: Vec<AgentAddress>,
+}
+
+impl ProcessFlows {
+    fn add_subjects(&mut self, resource: &Option<EconomicResourceAddress>, specification: &Option<ResourceSpecificationAddress>) {
+        if let Some(resource) = resource {
+            if !self.resources.contains(resource) {
+                self.resources.push(resource.to_owned());
+            }
+        }
+        if let Some(specification) = specification {
+            if !self.specifications.contains(specification) {
+                self.specifications.push(specification.to_owned());
+            }
+        }
+    }
+
+    fn add_working_agent(&mut self, is_work: bool, provider: &Option<AgentAddress>) {
+        if let (true, Some(provider)) = (is_work, provider) {
+            if !self.working_agents.contains(provider) {
+                self.working_agents.push(provider.to_owned());
+            }
+        }
+    }
+
+    /// Only the resource an event is inventoried as flows through the Process. Any `to_resource_inventoried_as`
+    /// is a container or destination acted upon by the flow, not a resource exchanged by the Process.
+    fn add_observed_flow(&mut self, event: &ObservedFlow, is_input: bool) {
+        self.add_subjects(&event.resource_inventoried_as, &event.resource_conforms_to);
+        self.add_working_agent(is_input && is_work(&event.action), &Some(event.provider.to_owned()));
+    }
+
+    fn add_planned_flow(&mut self, flow: &PlannedFlow, is_input: bool) {
+        self.add_subjects(&flow.resource_inventoried_as, &flow.resource_conforms_to);
+        if let PlannedFlowAddress::Commitment(_) = flow.address {
+            self.add_working_agent(is_input && is_work(&flow.action), &flow.provider);
+        }
+    }
+
+    /// The resources, resource specifications & working agents of this set which are not in `other`
+    fn without(&self, other: &ProcessFlows) -> ProcessFlows {
+        ProcessFlows {
+            resources: self.resources.iter().filter(|id| !other.resources.contains(id)).cloned().collect(),
+            specifications: self.specifications.iter().filter(|id| !other.specifications.contains(id)).cloned().collect(),
+            working_agents: self.working_agents.iter().filter(|id| !other.working_agents.contains(id)).cloned().collect(),
+        }
+    }
+}
+
+fn observed_flow(address: &EconomicEventAddress, event: &EventData) -> ObservedFlow {
+    ObservedFlow {
+        address: address.to_owned(),
+        action: event.action.to_owned(),
+        provider: event.provider.to_owned(),
+        resource_inventoried_as: event.resource_inventoried_as.to_owned(),
+        resource_conforms_to: event.resource_conforms_to.to_owned(),
+    }
+}
+
+/// Determine the Processes which follow on from (`next_processes`) and lead into
+/// (`previous_processes`) a Process.
+///
+/// Process A precedes Process B when any observed or planned output of A concerns the
+/// same resource or resource specification as any observed or planned input of B.
+///
+fn get_adjacent_processes(process: &ProcessAddress) -> RecordAPIResult<(Vec<ProcessAddress>, Vec<ProcessAddress>)> {
+    let mut next_processes = vec![];
+    let mut previous_processes = vec![];
+
+    let output_resources: Vec<EconomicResourceAddress> = read_index!(process(process).output_resources)?;
+    for resource in output_resources.iter() {
+        add_adjacent_processes(&mut next_processes, process, read_index!(economic_resource(resource).flows_into)?);
+    }
+    let output_specifications: Vec<ResourceSpecificationAddress> = read_index!(process(process).output_specifications)?;
+    for specification in output_specifications.iter() {
+        add_adjacent_processes(&mut next_processes, process, read_index!(resource_specification(specification).flows_into)?);
+    }
+
+    let input_resources: Vec<EconomicResourceAddress> = read_index!(process(process).input_resources)?;
+    for resource in input_resources.iter() {
+        add_adjacent_processes(&mut previous_processes, process, read_index!(economic_resource(resource).flows_out_of)?);
+    }
+    let input_specifications: Vec<ResourceSpecificationAddress> = read_index!(process(process).input_specifications)?;
+    for specification in input_specifications.iter() {
+        add_adjacent_processes(&mut previous_processes, process, read_index!(resource_specification(specification).flows_out_of)?);
+    }
+
+    Ok((next_processes, previous_processes))
+}
+
+fn add_adjacent_processes(adjacent: &mut Vec<ProcessAddress>, process: &ProcessAddress, candidates: Vec<ProcessAddress>) {
+    for candidate in candidates {
+        if candidate != *process && !adjacent.contains(&candidate) {
+            adjacent.push(candidate);
+        }
+    }
+}
+
+/// Collect the resources, resource specifications & working agents concerned by a set of
+/// observed and planned flows on one side of a Process.
+///
+fn read_flows(
+    events: &[EconomicEventAddress],
+    planned: &[PlannedFlow],
+    is_input: bool,
+) -> RecordAPIResult<ProcessFlows> {
+    let mut flows = ProcessFlows::default();
+
+    for (address, event) in read_process_events(EVENT_ENTRY_TYPE, events.to_vec())? {
+        flows.add_observed_flow(&observed_flow(&address, &event), is_input);
+    }
+    for flow in planned {
+        flows.add_planned_flow(flow, is_input);
+    }
+
+    Ok(flows)
+}
@@ -10,7 +10,7 @@ use serde_maybe_undefined::{
 pub use vf_attributes_hdk::{
     RevisionHash,
     ProcessAddress,
-    Timestamp,
+    DateTimeValue,
     ExternalURL,
     ProcessSpecificationAddress,
     PlanAddress,
@@ -18,6 +18,9 @@ pub use vf_attributes_hdk::{
     CommitmentAddress,
     IntentAddress,
     AgentAddress,
+    EconomicResourceAddress,
+    ResourceSpecificationAddress,
+    ActionId,
 };
 
 //---------------- EXTERNAL RECORD STRUCTURE ----------------
@@ -30,13 +33,13 @@ pub struct Response {
     pub revision_id: RevisionHash,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_beginning: Option<Timestamp>,
+    pub has_beginning: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_end: Option<Timestamp>,
+    pub has_end: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub before: Option<Timestamp>,
+    pub before: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub after: Option<Timestamp>,
+    pub after: Option<DateTimeValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub classified_as: Option<Vec<ExternalURL>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,6 +99,16 @@ pub struct ResponseData {
     pub process: Response,
 }
 
+/// I/O struct to describe the outcome of checking paired flows (eg. `accept` / `modify`) within a Process.
+/// Flows are only checked once the Process is `finished`.
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowValidationResponse {
+    pub process: ProcessAddress,
+    pub finished: bool,
+    pub warnings: Vec<String>,
+}
+
 //---------------- CREATE REQUEST ----------------
 
 /// I/O struct to describe the complete input record, including all managed links
@@ -104,13 +117,13 @@ pub struct ResponseData {
 pub struct CreateRequest {
     pub name: String,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<Timestamp>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<Timestamp>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub before: MaybeUndefined<Timestamp>,
+    pub before: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub after: MaybeUndefined<Timestamp>,
+    pub after: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub classified_as: MaybeUndefined<Vec<ExternalURL>>,
     #[serde(default)]
@@ -122,6 +135,8 @@ pub struct CreateRequest {
     #[serde(default)]
     pub in_scope_of: MaybeUndefined<Vec<String>>,
     #[serde(default)]
+    pub idempotency_key: MaybeUndefined<String>,
+    #[serde(default)]
     pub note: MaybeUndefined<String>,
 }
 
@@ -139,13 +154,13 @@ pub struct UpdateRequest {
     #[serde(default)]
     pub name: MaybeUndefined<String>,
     #[serde(default)]
-    pub has_beginning: MaybeUndefined<Timestamp>,
+    pub has_beginning: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub has_end: MaybeUndefined<Timestamp>,
+    pub has_end: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub before: MaybeUndefined<Timestamp>,
+    pub before: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
-    pub after: MaybeUndefined<Timestamp>,
+    pub after: MaybeUndefined<DateTimeValue>,
     #[serde(default)]
     pub classified_as: MaybeUndefined<Vec<ExternalURL>>,
     #[serde(default)]
@@ -168,6 +183,57 @@ impl<'a> UpdateRequest {
     // :TODO: accessors for other field data
 }
 
+//---------------- FLOW INDEXING REQUEST ----------------
+
+/// Parameters for `index_process_flows`, called by the zomes managing the flows into & out of a Process
+/// whenever those flows are created, changed or removed.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct IndexFlowsParams {
+    pub address: ProcessAddress,
+    pub update: FlowsUpdate,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub enum FlowsUpdate {
+    /// An EconomicEvent was recorded as an input or output of the Process. Only the resources,
+    /// resource specification & working agent of this event are added to the Process's indexes.
+    EventAdded { event: ObservedFlow, is_input: bool },
+    /// An EconomicEvent was removed from the inputs or outputs of the Process. Its resources, resource
+    /// specification & working agent are removed from the Process's indexes where no other flow
+    /// of the Process refers to them.
+    EventRemoved { event: ObservedFlow, is_input: bool },
+    /// The planned flows of the Process have changed. All Commitments & Intents which are
+    /// inputs and outputs of the Process are given, so that they need not be read back from the planning DNA.
+    PlannedFlowsChanged { inputs: Vec<PlannedFlow>, outputs: Vec<PlannedFlow> },
+}
+
+/// The fields of an EconomicEvent which determine how a Process is indexed
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct ObservedFlow {
+    pub address: EconomicEventAddress,
+    pub action: ActionId,
+    pub provider: AgentAddress,
+    pub resource_inventoried_as: Option<EconomicResourceAddress>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub enum PlannedFlowAddress {
+    Commitment(CommitmentAddress),
+    Intent(IntentAddress),
+}
+
+/// The fields of a Commitment or Intent which determine how a Process is indexed
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+pub struct PlannedFlow {
+    pub address: PlannedFlowAddress,
+    pub action: ActionId,
+    pub provider: Option<AgentAddress>,
+    pub resource_inventoried_as: Option<EconomicResourceAddress>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+}
+
 //---------------- QUERY FILTER REQUEST ----------------
 
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
@@ -181,4 +247,13 @@ pub struct QueryParams {
     pub intended_inputs: Option<IntentAddress>,
     pub intended_outputs: Option<IntentAddress>,
     pub working_agents: Option<AgentAddress>,
+    pub planned_within: Option<PlanAddress>,
+    #[serde(default)]
+    pub input_resources: Option<EconomicResourceAddress>,
+    #[serde(default)]
+    pub output_resources: Option<EconomicResourceAddress>,
+    #[serde(default)]
+    pub input_specifications: Option<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub output_specifications: Option<ResourceSpecificationAddress>,
 }
@@ -16,10 +16,11 @@ use hdk_records::{
 
 use vf_attributes_hdk::{
     ProcessAddress,
-    Timestamp,
+    DateTimeValue,
     ExternalURL,
     ProcessSpecificationAddress,
     PlanAddress,
+    validate_date_order,
 };
 
 use hc_zome_rea_process_rpc::{ CreateRequest, UpdateRequest };
@@ -35,6 +36,11 @@ pub struct DnaConfigSlice {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
 pub struct ProcessZomeConfig {
     pub index_zome: String,
+    // zome ID of the `EconomicEvent` index zome, used to determine which events fulfill planned commitments
+    pub economic_event_index_zome: Option<String>,
+    // zome IDs of the `EconomicResource` & `ResourceSpecification` index zomes, used to connect Processes via the flows between them
+    pub economic_resource_index_zome: Option<String>,
+    pub resource_specification_index_zome: Option<String>,
 }
 
 //---------------- RECORD INTERNALS & VALIDATION ----------------
@@ -42,16 +48,27 @@ pub struct ProcessZomeConfig {
 #[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
 pub struct EntryData {
     pub name: String,
-    pub has_beginning: Option<Timestamp>,
-    pub has_end: Option<Timestamp>,
-    pub before: Option<Timestamp>,
-    pub after: Option<Timestamp>,
+    pub has_beginning: Option<DateTimeValue>,
+    pub has_end: Option<DateTimeValue>,
+    pub before: Option<DateTimeValue>,
+    pub after: Option<DateTimeValue>,
     pub classified_as: Option<Vec<ExternalURL>>,
     pub based_on: Option<ProcessSpecificationAddress>,
     pub planned_within: Option<PlanAddress>,
     pub finished: bool,
     pub in_scope_of: Option<Vec<String>>,
     pub note: Option<String>,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+}
+
+impl EntryData {
+    pub fn validate_dates(&self) -> Result<(), String> {
+        validate_date_order(&self.has_beginning, "hasBeginning", &self.has_end, "hasEnd")
+            .and_then(|()| validate_date_order(&self.after, "after", &self.before, "before"))
+            .and_then(|()| validate_date_order(&self.after, "after", &self.has_beginning, "hasBeginning"))
+            .and_then(|()| validate_date_order(&self.has_end, "hasEnd", &self.before, "before"))
+    }
 }
 
 generate_record_entry!(EntryData, ProcessAddress, EntryStorage);
@@ -72,6 +89,7 @@ impl From<CreateRequest> for EntryData {
             planned_within: e.planned_within.into(),
             finished: e.finished.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_*" bindings via Serde
             in_scope_of: e.in_scope_of.into(),
+            idempotency_key: e.idempotency_key.into(),
             note: e.note.into(),
         }
     }
@@ -93,6 +111,7 @@ impl Updateable<UpdateRequest> for EntryData {
             planned_within: if e.planned_within == MaybeUndefined::Undefined { self.planned_within.to_owned() } else { e.planned_within.to_owned().into() },
             finished: if e.finished == MaybeUndefined::Undefined { self.finished.to_owned() } else { e.finished.to_owned().to_option().unwrap() },
             in_scope_of: if e.in_scope_of == MaybeUndefined::Undefined { self.in_scope_of.to_owned() } else { e.in_scope_of.to_owned().into() },
+            idempotency_key: self.idempotency_key.to_owned(),
             note: if e.note == MaybeUndefined::Undefined { self.note.to_owned() } else { e.note.to_owned().into() },
         }
     }
@@ -19,3 +19,4 @@ pub const PROCESS_COMMITMENT_INPUTS_READ_API_METHOD: &str = "_internal_read_proc
 pub const PROCESS_COMMITMENT_OUTPUTS_READ_API_METHOD: &str = "_internal_read_process_committed_outputs";
 pub const PROCESS_INTENT_INPUTS_READ_API_METHOD: &str = "_internal_read_process_intended_inputs";
 pub const PROCESS_INTENT_OUTPUTS_READ_API_METHOD: &str = "_internal_read_process_intended_outputs";
+pub const PROCESS_FLOWS_INDEXING_API_METHOD: &str = "index_process_flows";
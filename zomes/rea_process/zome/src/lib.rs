@@ -10,13 +10,42 @@
 use hdk::prelude::*;
 
 use hc_zome_rea_process_storage_consts::*;
+use hc_zome_rea_economic_event_storage_consts::EVENT_ENTRY_TYPE;
 use hc_zome_rea_process_lib::*;
 use hc_zome_rea_process_rpc::*;
+use hc_zome_rea_process_storage::*;
+
+#[hdk_extern]
+fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResult> {
+    let element = validation_data.element;
+    let entry = element.into_inner().1;
+    let entry = match entry {
+        ElementEntry::Present(e) => e,
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+
+    match EntryStorage::try_from(&entry) {
+        Ok(process_storage) => {
+            let record = process_storage.entry();
+            record.validate_dates()
+                .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
+                .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
 
 #[hdk_extern]
 fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
     Ok(EntryDefsCallbackResult::from(vec![
         PathEntry::entry_def(),
+        EntryDef {
+            id: CAP_STORAGE_ENTRY_DEF_ID.into(),
+            visibility: EntryVisibility::Private,
+            crdt_type: CrdtType,
+            required_validations: 1.into(),
+            required_validation_type: RequiredValidationType::default(),
+        },
         EntryDef {
             id: PROCESS_ENTRY_TYPE.into(),
             visibility: EntryVisibility::Public,
@@ -37,6 +66,11 @@ fn create_process(CreateParams { process }: CreateParams) -> ExternResult<Respon
     Ok(handle_create_process(PROCESS_ENTRY_TYPE, process)?)
 }
 
+#[hdk_extern]
+fn _internal_create_planned_process(CreateParams { process }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_planned_process(PROCESS_ENTRY_TYPE, process)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ReadParams {
     pub address: ProcessAddress,
@@ -47,6 +81,16 @@ fn get_process(ReadParams { address }: ReadParams) -> ExternResult<ResponseData>
     Ok(handle_get_process(PROCESS_ENTRY_TYPE, address)?)
 }
 
+#[hdk_extern]
+fn validate_process_flows(ReadParams { address }: ReadParams) -> ExternResult<FlowValidationResponse> {
+    Ok(handle_validate_process_flows(PROCESS_ENTRY_TYPE, EVENT_ENTRY_TYPE, address)?)
+}
+
+#[hdk_extern]
+fn index_process_flows(params: IndexFlowsParams) -> ExternResult<()> {
+    Ok(handle_index_process_flows(PROCESS_ENTRY_TYPE, params)?)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct UpdateParams {
     pub process: UpdateRequest,
@@ -66,3 +110,8 @@ struct DeleteParams {
 fn delete_process(DeleteParams { address }: DeleteParams) -> ExternResult<bool> {
     Ok(handle_delete_process(PROCESS_ENTRY_TYPE, address)?)
 }
+
+#[hdk_extern]
+fn _internal_delete_planned_process(DeleteParams { address }: DeleteParams) -> ExternResult<bool> {
+    Ok(handle_delete_planned_process(PROCESS_ENTRY_TYPE, address)?)
+}
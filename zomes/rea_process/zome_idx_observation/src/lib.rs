@@ -14,5 +14,13 @@ struct Process {
     committed_inputs: Remote<commitment, input_of>,
     committed_outputs: Remote<commitment, output_of>,
     intended_inputs: Remote<intent, input_of>,
-    intended_outputs: Remote<intent, output_of>
+    intended_outputs: Remote<intent, output_of>,
+    planned_within: Local<plan, processes>,
+    working_agents: Local<agent, worked_on>,
+
+    // internal indexes (not part of REA spec)
+    input_resources: Local<economic_resource, flows_into>,
+    output_resources: Local<economic_resource, flows_out_of>,
+    input_specifications: Local<resource_specification, flows_into>,
+    output_specifications: Local<resource_specification, flows_out_of>,
 }
@@ -0,0 +1,86 @@
+/**
+ * Holo-REA recipe exchange zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeExchange` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_exchange_storage::*;
+use hc_zome_rea_recipe_exchange_rpc::*;
+
+pub use hc_zome_rea_recipe_exchange_storage::RECIPE_EXCHANGE_ENTRY_TYPE;
+
+pub fn handle_create_recipe_exchange<S>(entry_def_id: S, recipe_exchange: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, recipe_exchange)?;
+    construct_response(&base_address, &revision_id, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_recipe_exchange<S>(entry_def_id: S, address: RecipeExchangeAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_update_recipe_exchange<S>(entry_def_id: S, recipe_exchange: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let old_revision = recipe_exchange.get_revision_id();
+    let (revision_id, base_address, new_entry, _prev_entry): (_, RecipeExchangeAddress, EntryData, EntryData) = update_record(&entry_def_id, old_revision, recipe_exchange.to_owned())?;
+    construct_response(&base_address, &revision_id, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_recipe_exchange(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeExchangeAddress, revision_id: &RevisionHash, e: &EntryData, (
+        recipe_clauses,
+    ): (
+        Vec<RecipeFlowAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_exchange: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            name: e.name.to_owned(),
+            note: e.note.to_owned(),
+            recipe_clauses: recipe_clauses.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_exchange_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_exchange.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &RecipeExchangeAddress) -> RecordAPIResult<(
+    Vec<RecipeFlowAddress>,
+)> {
+    Ok((
+        read_index!(recipe_exchange(base_address).recipe_clauses)?,
+    ))
+}
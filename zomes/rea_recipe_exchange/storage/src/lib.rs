@@ -0,0 +1,65 @@
+/**
+ * Holo-REA recipe exchange zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+use hc_zome_rea_recipe_exchange_rpc::{CreateRequest, RecipeExchangeAddress, UpdateRequest};
+
+pub use hc_zome_rea_recipe_exchange_storage_consts::RECIPE_EXCHANGE_ENTRY_TYPE;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_exchange: RecipeExchangeZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeExchangeZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub note: Option<String>,
+}
+
+generate_record_entry!(EntryData, RecipeExchangeAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            name: e.name.into(),
+            note: e.note.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+        }
+    }
+}
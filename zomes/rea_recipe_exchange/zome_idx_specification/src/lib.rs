@@ -0,0 +1,13 @@
+/**
+ * RecipeExchange query indexes for specification DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_exchange_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct RecipeExchange {
+    recipe_clauses: Local<recipe_flow, recipe_clause_of>,
+}
@@ -0,0 +1,194 @@
+/**
+ * Holo-REA recipe flow zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeFlow` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_flow_storage::*;
+use hc_zome_rea_recipe_flow_rpc::*;
+
+pub use hc_zome_rea_recipe_flow_storage::RECIPE_FLOW_ENTRY_TYPE;
+
+pub fn handle_create_recipe_flow<S>(entry_def_id: S, recipe_flow: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, recipe_flow)?;
+
+    // handle link fields
+    if let Some(recipe_input_of) = &entry_resp.recipe_input_of {
+        create_index!(Local(recipe_flow.recipe_input_of(recipe_input_of), recipe_process.recipe_inputs(&base_address)))?;
+    };
+    if let Some(recipe_output_of) = &entry_resp.recipe_output_of {
+        create_index!(Local(recipe_flow.recipe_output_of(recipe_output_of), recipe_process.recipe_outputs(&base_address)))?;
+    };
+    if let Some(recipe_flow_resource) = &entry_resp.recipe_flow_resource {
+        create_index!(Local(recipe_flow.recipe_flow_resource(recipe_flow_resource), recipe_resource.recipe_flows(&base_address)))?;
+    };
+    if let Some(recipe_clause_of) = &entry_resp.recipe_clause_of {
+        create_index!(Local(recipe_flow.recipe_clause_of(recipe_clause_of), recipe_exchange.recipe_clauses(&base_address)))?;
+    };
+    if let Some(resource_conforms_to) = &entry_resp.resource_conforms_to {
+        create_index!(Local(recipe_flow.resource_conforms_to(resource_conforms_to), resource_specification.recipe_flows(&base_address)))?;
+    };
+
+    construct_response(&base_address, &revision_id, &entry_resp)
+}
+
+pub fn handle_get_recipe_flow<S>(entry_def_id: S, address: RecipeFlowAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry)
+}
+
+pub fn handle_update_recipe_flow<S>(entry_def_id: S, recipe_flow: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let old_revision = recipe_flow.get_revision_id();
+    let (revision_id, base_address, new_entry, prev_entry): (_, RecipeFlowAddress, EntryData, EntryData) = update_record(&entry_def_id, old_revision, recipe_flow.to_owned())?;
+
+    // handle link fields
+    if new_entry.recipe_input_of != prev_entry.recipe_input_of {
+        let new_value = match &new_entry.recipe_input_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.recipe_input_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            recipe_flow
+                .recipe_input_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            recipe_process.recipe_inputs(&base_address)
+        ))?;
+    }
+    if new_entry.recipe_output_of != prev_entry.recipe_output_of {
+        let new_value = match &new_entry.recipe_output_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.recipe_output_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            recipe_flow
+                .recipe_output_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            recipe_process.recipe_outputs(&base_address)
+        ))?;
+    }
+    if new_entry.recipe_flow_resource != prev_entry.recipe_flow_resource {
+        let new_value = match &new_entry.recipe_flow_resource { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.recipe_flow_resource { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            recipe_flow
+                .recipe_flow_resource(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            recipe_resource.recipe_flows(&base_address)
+        ))?;
+    }
+    if new_entry.recipe_clause_of != prev_entry.recipe_clause_of {
+        let new_value = match &new_entry.recipe_clause_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.recipe_clause_of { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            recipe_flow
+                .recipe_clause_of(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            recipe_exchange.recipe_clauses(&base_address)
+        ))?;
+    }
+    if new_entry.resource_conforms_to != prev_entry.resource_conforms_to {
+        let new_value = match &new_entry.resource_conforms_to { Some(val) => vec![val.to_owned()], None => vec![] };
+        let prev_value = match &prev_entry.resource_conforms_to { Some(val) => vec![val.to_owned()], None => vec![] };
+        update_index!(Local(
+            recipe_flow
+                .resource_conforms_to(new_value.as_slice())
+                .not(prev_value.as_slice()),
+            resource_specification.recipe_flows(&base_address)
+        ))?;
+    }
+
+    construct_response(&base_address, &revision_id, &new_entry)
+}
+
+pub fn handle_delete_recipe_flow(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    // load the record to ensure it is of the correct type
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    if let Some(process_address) = entry.recipe_input_of {
+        update_index!(Local(recipe_flow.recipe_input_of.not(&vec![process_address]), recipe_process.recipe_inputs(&base_address)))?;
+    }
+    if let Some(process_address) = entry.recipe_output_of {
+        update_index!(Local(recipe_flow.recipe_output_of.not(&vec![process_address]), recipe_process.recipe_outputs(&base_address)))?;
+    }
+    if let Some(resource_address) = entry.recipe_flow_resource {
+        update_index!(Local(recipe_flow.recipe_flow_resource.not(&vec![resource_address]), recipe_resource.recipe_flows(&base_address)))?;
+    }
+    if let Some(exchange_address) = entry.recipe_clause_of {
+        update_index!(Local(recipe_flow.recipe_clause_of.not(&vec![exchange_address]), recipe_exchange.recipe_clauses(&base_address)))?;
+    }
+    if let Some(specification_address) = entry.resource_conforms_to {
+        update_index!(Local(recipe_flow.resource_conforms_to.not(&vec![specification_address]), resource_specification.recipe_flows(&base_address)))?;
+    }
+
+    // delete entry last, as it must be present in order for links to be removed
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeFlowAddress, revision_id: &RevisionHash, e: &EntryData,
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_flow: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            action: e.action.to_owned(),
+            recipe_input_of: e.recipe_input_of.to_owned(),
+            recipe_output_of: e.recipe_output_of.to_owned(),
+            recipe_flow_resource: e.recipe_flow_resource.to_owned(),
+            resource_conforms_to: e.resource_conforms_to.to_owned(),
+            stage: e.stage.to_owned(),
+            state: e.state.to_owned(),
+            resource_quantity: e.resource_quantity.to_owned(),
+            effort_quantity: e.effort_quantity.to_owned(),
+            recipe_clause_of: e.recipe_clause_of.to_owned(),
+            note: e.note.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_flow_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_flow.index_zome)
+}
+
+/// Properties accessor for zome config
+fn read_recipe_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.recipe_flow.recipe_process_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_recipe_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.recipe_flow.recipe_resource_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_recipe_exchange_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.recipe_flow.recipe_exchange_index_zome
+}
+
+/// Properties accessor for zome config
+fn read_resource_specification_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    conf.recipe_flow.resource_specification_index_zome
+}
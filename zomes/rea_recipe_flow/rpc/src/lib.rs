@@ -0,0 +1,161 @@
+/**
+ * Holo-REA recipe flow zome I/O data structures
+ *
+ * Required by packages wishing to interact with the zome via its standard RPC interface,
+ * and by the zome API handlers accepting these parameters.
+ *
+ * @package Holo-REA
+ */
+use holochain_serialized_bytes::prelude::*;
+use serde_maybe_undefined::MaybeUndefined;
+pub use vf_measurement::QuantityValue;
+pub use vf_attributes_hdk::{
+    RevisionHash, ByAddress, ByHeader,
+    ActionId,
+    RecipeFlowAddress,
+    RecipeProcessAddress,
+    RecipeResourceAddress,
+    RecipeExchangeAddress,
+    ResourceSpecificationAddress,
+    ProcessSpecificationAddress,
+};
+
+// toplevel I/O structs for WASM API
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateParams {
+    pub recipe_flow: CreateRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateParams {
+    pub recipe_flow: UpdateRequest,
+}
+
+//---------------- EXTERNAL RECORD STRUCTURE ----------------
+
+/// I/O struct to describe the complete record, including all managed link fields
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    pub id: RecipeFlowAddress,
+    pub revision_id: RevisionHash,
+    pub action: ActionId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_flow_resource: Option<RecipeResourceAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ProcessSpecificationAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort_quantity: Option<QuantityValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// I/O struct to describe what is returned outside the gateway.
+/// Responses are usually returned as named attributes in order to leave space
+/// for future additional return values.
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseData {
+    pub recipe_flow: Response,
+}
+
+//---------------- CREATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRequest {
+    pub action: ActionId,
+    #[serde(default)]
+    pub recipe_input_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_output_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_flow_resource: MaybeUndefined<RecipeResourceAddress>,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub stage: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub state: MaybeUndefined<String>,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub recipe_clause_of: MaybeUndefined<RecipeExchangeAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> CreateRequest {
+    // :TODO: accessors for field data
+}
+
+//---------------- UPDATE REQUEST ----------------
+
+/// I/O struct to describe the complete input record, including all managed links
+///
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRequest {
+    pub revision_id: RevisionHash,
+    #[serde(default)]
+    pub action: MaybeUndefined<ActionId>,
+    #[serde(default)]
+    pub recipe_input_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_output_of: MaybeUndefined<RecipeProcessAddress>,
+    #[serde(default)]
+    pub recipe_flow_resource: MaybeUndefined<RecipeResourceAddress>,
+    #[serde(default)]
+    pub resource_conforms_to: MaybeUndefined<ResourceSpecificationAddress>,
+    #[serde(default)]
+    pub stage: MaybeUndefined<ProcessSpecificationAddress>,
+    #[serde(default)]
+    pub state: MaybeUndefined<String>,
+    #[serde(default)]
+    pub resource_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub effort_quantity: MaybeUndefined<QuantityValue>,
+    #[serde(default)]
+    pub recipe_clause_of: MaybeUndefined<RecipeExchangeAddress>,
+    #[serde(default)]
+    pub note: MaybeUndefined<String>,
+}
+
+impl<'a> UpdateRequest {
+    pub fn get_revision_id(&'a self) -> &RevisionHash {
+        &self.revision_id
+    }
+
+    // :TODO: accessors for other field data
+}
+
+//---------------- QUERY FILTER REQUEST ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParams {
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    pub recipe_flow_resource: Option<RecipeResourceAddress>,
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+}
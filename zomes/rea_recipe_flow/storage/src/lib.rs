@@ -0,0 +1,116 @@
+/**
+ * Holo-REA recipe flow zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+use vf_measurement::QuantityValue;
+use vf_attributes_hdk::{
+    ActionId,
+    RecipeProcessAddress,
+    RecipeResourceAddress,
+    RecipeExchangeAddress,
+    ResourceSpecificationAddress,
+    ProcessSpecificationAddress,
+};
+use vf_actions::{ validate_flow_action };
+
+use hc_zome_rea_recipe_flow_rpc::{CreateRequest, RecipeFlowAddress, UpdateRequest};
+
+pub use hc_zome_rea_recipe_flow_storage_consts::RECIPE_FLOW_ENTRY_TYPE;
+
+// :SHONK: needed as re-export in zome logic to allow validation logic to parse entries
+pub use hdk_records::record_interface::Identified;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_flow: RecipeFlowZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeFlowZomeConfig {
+    pub index_zome: String,
+    pub recipe_process_index_zome: Option<String>,
+    pub recipe_resource_index_zome: Option<String>,
+    pub recipe_exchange_index_zome: Option<String>,
+    pub resource_specification_index_zome: Option<String>,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub action: ActionId,
+    pub recipe_input_of: Option<RecipeProcessAddress>,
+    pub recipe_output_of: Option<RecipeProcessAddress>,
+    pub recipe_flow_resource: Option<RecipeResourceAddress>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    pub stage: Option<ProcessSpecificationAddress>,
+    pub state: Option<String>,
+    pub resource_quantity: Option<QuantityValue>,
+    pub effort_quantity: Option<QuantityValue>,
+    pub recipe_clause_of: Option<RecipeExchangeAddress>,
+    pub note: Option<String>,
+}
+
+impl EntryData {
+    pub fn validate_action(&self) -> Result<(), String> {
+        validate_flow_action("RecipeFlow", self.action.to_owned(), self.recipe_input_of.to_owned(), self.recipe_output_of.to_owned())
+    }
+}
+
+generate_record_entry!(EntryData, RecipeFlowAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            action: e.action.to_owned(),
+            recipe_input_of: e.recipe_input_of.into(),
+            recipe_output_of: e.recipe_output_of.into(),
+            recipe_flow_resource: e.recipe_flow_resource.into(),
+            resource_conforms_to: e.resource_conforms_to.into(),
+            stage: e.stage.into(),
+            state: e.state.into(),
+            resource_quantity: e.resource_quantity.into(),
+            effort_quantity: e.effort_quantity.into(),
+            recipe_clause_of: e.recipe_clause_of.into(),
+            note: e.note.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            action: if !e.action.is_some() { self.action.to_owned() } else { e.action.to_owned().unwrap() },
+            recipe_input_of: if e.recipe_input_of.is_undefined() { self.recipe_input_of.to_owned() } else { e.recipe_input_of.to_owned().into() },
+            recipe_output_of: if e.recipe_output_of.is_undefined() { self.recipe_output_of.to_owned() } else { e.recipe_output_of.to_owned().into() },
+            recipe_flow_resource: if e.recipe_flow_resource.is_undefined() { self.recipe_flow_resource.to_owned() } else { e.recipe_flow_resource.to_owned().into() },
+            resource_conforms_to: if e.resource_conforms_to.is_undefined() { self.resource_conforms_to.to_owned() } else { e.resource_conforms_to.to_owned().into() },
+            stage: if e.stage.is_undefined() { self.stage.to_owned() } else { e.stage.to_owned().into() },
+            state: if e.state.is_undefined() { self.state.to_owned() } else { e.state.to_owned().into() },
+            resource_quantity: if e.resource_quantity.is_undefined() { self.resource_quantity.to_owned() } else { e.resource_quantity.to_owned().into() },
+            effort_quantity: if e.effort_quantity.is_undefined() { self.effort_quantity.to_owned() } else { e.effort_quantity.to_owned().into() },
+            recipe_clause_of: if e.recipe_clause_of.is_undefined() { self.recipe_clause_of.to_owned() } else { e.recipe_clause_of.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const RECIPE_FLOW_ENTRY_TYPE: &str = "vf_recipe_flow";
+pub const RECIPE_FLOW_RECIPE_INPUT_OF_LINK_TAG: &str = "recipe_input_of";
+pub const RECIPE_FLOW_RECIPE_OUTPUT_OF_LINK_TAG: &str = "recipe_output_of";
+pub const RECIPE_FLOW_RECIPE_FLOW_RESOURCE_LINK_TAG: &str = "recipe_flow_resource";
+pub const RECIPE_FLOW_RECIPE_CLAUSE_OF_LINK_TAG: &str = "recipe_clause_of";
+pub const RECIPE_FLOW_RESOURCE_CONFORMS_TO_LINK_TAG: &str = "resource_conforms_to";
@@ -0,0 +1,69 @@
+/**
+ * Holo-REA recipe flow zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_recipe_flow_rpc::*;
+use hc_zome_rea_recipe_flow_lib::*;
+use hc_zome_rea_recipe_flow_storage::*;
+use hc_zome_rea_recipe_flow_storage_consts::*;
+
+#[hdk_extern]
+fn validate(validation_data: ValidateData) -> ExternResult<ValidateCallbackResult> {
+    let element = validation_data.element;
+    let entry = element.into_inner().1;
+    let entry = match entry {
+        ElementEntry::Present(e) => e,
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+
+    match EntryStorage::try_from(&entry) {
+        Ok(recipe_flow_storage) => {
+            let record = recipe_flow_storage.entry();
+            record.validate_action()
+                .and_then(|()| { Ok(ValidateCallbackResult::Valid) })
+                .or_else(|e| { Ok(ValidateCallbackResult::Invalid(e)) })
+        },
+        _ => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: RECIPE_FLOW_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 2.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[hdk_extern]
+fn create_recipe_flow(CreateParams { recipe_flow }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_recipe_flow(RECIPE_FLOW_ENTRY_TYPE, recipe_flow)?)
+}
+
+#[hdk_extern]
+fn get_recipe_flow(ByAddress { address }: ByAddress<RecipeFlowAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_recipe_flow(RECIPE_FLOW_ENTRY_TYPE, address)?)
+}
+
+#[hdk_extern]
+fn update_recipe_flow(UpdateParams { recipe_flow }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_recipe_flow(RECIPE_FLOW_ENTRY_TYPE, recipe_flow)?)
+}
+
+#[hdk_extern]
+fn delete_recipe_flow(ByHeader { address }: ByHeader) -> ExternResult<bool> {
+    Ok(handle_delete_recipe_flow(address)?)
+}
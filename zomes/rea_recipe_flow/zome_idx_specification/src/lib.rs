@@ -0,0 +1,17 @@
+/**
+ * RecipeFlow query indexes for specification DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_flow_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct RecipeFlow {
+    recipe_input_of: Local<recipe_process, recipe_inputs>,
+    recipe_output_of: Local<recipe_process, recipe_outputs>,
+    recipe_flow_resource: Local<recipe_resource, recipe_flows>,
+    recipe_clause_of: Local<recipe_exchange, recipe_clauses>,
+    resource_conforms_to: Local<resource_specification, recipe_flows>,
+}
@@ -0,0 +1,95 @@
+/**
+ * Holo-REA recipe process zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeProcess` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_process_storage::*;
+use hc_zome_rea_recipe_process_rpc::*;
+
+pub use hc_zome_rea_recipe_process_storage::RECIPE_PROCESS_ENTRY_TYPE;
+
+pub fn handle_create_recipe_process<S>(entry_def_id: S, recipe_process: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, recipe_process)?;
+    construct_response(&base_address, &revision_id, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_recipe_process<S>(entry_def_id: S, address: RecipeProcessAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_update_recipe_process<S>(entry_def_id: S, recipe_process: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let old_revision = recipe_process.get_revision_id();
+    let (revision_id, base_address, new_entry, _prev_entry): (_, RecipeProcessAddress, EntryData, EntryData) = update_record(&entry_def_id, old_revision, recipe_process.to_owned())?;
+    construct_response(&base_address, &revision_id, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_recipe_process(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeProcessAddress, revision_id: &RevisionHash, e: &EntryData, (
+        recipe_inputs,
+        recipe_outputs,
+    ): (
+        Vec<RecipeFlowAddress>,
+        Vec<RecipeFlowAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_process: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            name: e.name.to_owned(),
+            image: e.image.to_owned(),
+            note: e.note.to_owned(),
+            process_conforms_to: e.process_conforms_to.to_owned(),
+            process_classified_as: e.process_classified_as.to_owned(),
+            has_duration: e.has_duration.to_owned(),
+            recipe_inputs: recipe_inputs.to_owned(),
+            recipe_outputs: recipe_outputs.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_process_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_process.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &RecipeProcessAddress) -> RecordAPIResult<(
+    Vec<RecipeFlowAddress>,
+    Vec<RecipeFlowAddress>,
+)> {
+    Ok((
+        read_index!(recipe_process(base_address).recipe_inputs)?,
+        read_index!(recipe_process(base_address).recipe_outputs)?,
+    ))
+}
@@ -0,0 +1,82 @@
+/**
+ * Holo-REA recipe process zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+use vf_attributes_hdk::{
+    ExternalURL,
+    ProcessSpecificationAddress,
+};
+
+use hc_zome_rea_recipe_process_rpc::{CreateRequest, RecipeProcessAddress, UpdateRequest, Duration};
+
+pub use hc_zome_rea_recipe_process_storage_consts::RECIPE_PROCESS_ENTRY_TYPE;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_process: RecipeProcessZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeProcessZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub image: Option<ExternalURL>,
+    pub note: Option<String>,
+    pub process_conforms_to: Option<ProcessSpecificationAddress>,
+    pub process_classified_as: Option<Vec<ExternalURL>>,
+    pub has_duration: Option<Duration>,
+}
+
+generate_record_entry!(EntryData, RecipeProcessAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            name: e.name.into(),
+            image: e.image.into(),
+            note: e.note.into(),
+            process_conforms_to: e.process_conforms_to.into(),
+            process_classified_as: e.process_classified_as.into(),
+            has_duration: e.has_duration.into(),
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            image: if e.image.is_undefined() { self.image.to_owned() } else { e.image.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            process_conforms_to: if e.process_conforms_to.is_undefined() { self.process_conforms_to.to_owned() } else { e.process_conforms_to.to_owned().into() },
+            process_classified_as: if e.process_classified_as.is_undefined() { self.process_classified_as.to_owned() } else { e.process_classified_as.to_owned().into() },
+            has_duration: if e.has_duration.is_undefined() { self.has_duration.to_owned() } else { e.has_duration.to_owned().into() },
+        }
+    }
+}
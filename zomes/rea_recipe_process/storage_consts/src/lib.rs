@@ -0,0 +1,10 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const RECIPE_PROCESS_ENTRY_TYPE: &str = "vf_recipe_process";
+pub const RECIPE_PROCESS_RECIPE_INPUTS_LINK_TAG: &str = "recipe_inputs";
+pub const RECIPE_PROCESS_RECIPE_OUTPUTS_LINK_TAG: &str = "recipe_outputs";
@@ -0,0 +1,48 @@
+/**
+ * Holo-REA recipe process zome API definition
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_recipe_process_rpc::*;
+use hc_zome_rea_recipe_process_lib::*;
+use hc_zome_rea_recipe_process_storage_consts::*;
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: RECIPE_PROCESS_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 2.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[hdk_extern]
+fn create_recipe_process(CreateParams { recipe_process }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_recipe_process(RECIPE_PROCESS_ENTRY_TYPE, recipe_process)?)
+}
+
+#[hdk_extern]
+fn get_recipe_process(ByAddress { address }: ByAddress<RecipeProcessAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_recipe_process(RECIPE_PROCESS_ENTRY_TYPE, address)?)
+}
+
+#[hdk_extern]
+fn update_recipe_process(UpdateParams { recipe_process }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_recipe_process(RECIPE_PROCESS_ENTRY_TYPE, recipe_process)?)
+}
+
+#[hdk_extern]
+fn delete_recipe_process(ByHeader { address }: ByHeader) -> ExternResult<bool> {
+    Ok(handle_delete_recipe_process(address)?)
+}
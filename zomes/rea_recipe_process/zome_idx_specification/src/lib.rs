@@ -0,0 +1,14 @@
+/**
+ * RecipeProcess query indexes for specification DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_process_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome(query_fn_name="query_recipe_processes")]
+struct RecipeProcess {
+    recipe_inputs: Local<recipe_flow, recipe_input_of>,
+    recipe_outputs: Local<recipe_flow, recipe_output_of>,
+}
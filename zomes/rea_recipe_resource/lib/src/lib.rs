@@ -0,0 +1,92 @@
+/**
+ * Holo-REA recipe resource zome library API
+ *
+ * Contains helper methods that can be used to manipulate `RecipeResource` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_recipe_resource_storage::*;
+use hc_zome_rea_recipe_resource_rpc::*;
+
+pub use hc_zome_rea_recipe_resource_storage::RECIPE_RESOURCE_ENTRY_TYPE;
+
+pub fn handle_create_recipe_resource<S>(entry_def_id: S, recipe_resource: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision_id, base_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, recipe_resource)?;
+    construct_response(&base_address, &revision_id, &entry_resp, get_link_fields(&base_address)?)
+}
+
+pub fn handle_get_recipe_resource<S>(entry_def_id: S, address: RecipeResourceAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_update_recipe_resource<S>(entry_def_id: S, recipe_resource: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>,
+{
+    let old_revision = recipe_resource.get_revision_id();
+    let (revision_id, base_address, new_entry, _prev_entry): (_, RecipeResourceAddress, EntryData, EntryData) = update_record(&entry_def_id, old_revision, recipe_resource.to_owned())?;
+    construct_response(&base_address, &revision_id, &new_entry, get_link_fields(&base_address)?)
+}
+
+pub fn handle_delete_recipe_resource(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Create response from input DHT primitives
+fn construct_response<'a>(
+    address: &RecipeResourceAddress, revision_id: &RevisionHash, e: &EntryData, (
+        recipe_flows,
+    ): (
+        Vec<RecipeFlowAddress>,
+    ),
+) -> RecordAPIResult<ResponseData> {
+    Ok(ResponseData {
+        recipe_resource: Response {
+            id: address.to_owned(),
+            revision_id: revision_id.to_owned(),
+            name: e.name.to_owned(),
+            resource_classified_as: e.resource_classified_as.to_owned(),
+            resource_conforms_to: e.resource_conforms_to.to_owned(),
+            unit_of_resource: e.unit_of_resource.to_owned(),
+            unit_of_effort: e.unit_of_effort.to_owned(),
+            image: e.image.to_owned(),
+            note: e.note.to_owned(),
+            substitutable: e.substitutable,
+            recipe_flows: recipe_flows.to_owned(),
+        }
+    })
+}
+
+//---------------- READ ----------------
+
+/// Properties accessor for zome config
+fn read_recipe_resource_index_zome(conf: DnaConfigSlice) -> Option<String> {
+    Some(conf.recipe_resource.index_zome)
+}
+
+// @see construct_response
+fn get_link_fields(base_address: &RecipeResourceAddress) -> RecordAPIResult<(
+    Vec<RecipeFlowAddress>,
+)> {
+    Ok((
+        read_index!(recipe_resource(base_address).recipe_flows)?,
+    ))
+}
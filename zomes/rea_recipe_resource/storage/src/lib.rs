@@ -0,0 +1,90 @@
+/**
+ * Holo-REA recipe resource zome internal data structures
+ *
+ * Required by the zome itself, and for any DNA-local zomes interacting with its
+ * storage API directly.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hdk_records::{
+    MaybeUndefined,
+    record_interface::Updateable,
+    generate_record_entry,
+};
+
+use vf_attributes_hdk::{
+    ExternalURL,
+    UnitId,
+    ResourceSpecificationAddress,
+};
+
+use hc_zome_rea_recipe_resource_rpc::{CreateRequest, RecipeResourceAddress, UpdateRequest};
+
+pub use hc_zome_rea_recipe_resource_storage_consts::RECIPE_RESOURCE_ENTRY_TYPE;
+
+//--------------- ZOME CONFIGURATION ATTRIBUTES ----------------
+
+// :TODO: remove this, replace with reference to appropriate namespacing of zome config
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct DnaConfigSlice {
+    pub recipe_resource: RecipeResourceZomeConfig,
+}
+
+#[derive(Clone, Serialize, Deserialize, SerializedBytes, PartialEq, Debug)]
+pub struct RecipeResourceZomeConfig {
+    pub index_zome: String,
+}
+
+//---------------- RECORD INTERNALS & VALIDATION ----------------
+
+#[derive(Serialize, Deserialize, Debug, SerializedBytes, Clone)]
+pub struct EntryData {
+    pub name: String,
+    pub resource_classified_as: Option<Vec<ExternalURL>>,
+    pub resource_conforms_to: Option<ResourceSpecificationAddress>,
+    pub unit_of_resource: Option<UnitId>,
+    pub unit_of_effort: Option<UnitId>,
+    pub image: Option<ExternalURL>,
+    pub note: Option<String>,
+    pub substitutable: bool,
+}
+
+generate_record_entry!(EntryData, RecipeResourceAddress, EntryStorage);
+
+//---------------- CREATE ----------------
+
+/// Pick relevant fields out of I/O record into underlying DHT entry
+impl From<CreateRequest> for EntryData {
+    fn from(e: CreateRequest) -> EntryData {
+        EntryData {
+            name: e.name.into(),
+            resource_classified_as: e.resource_classified_as.into(),
+            resource_conforms_to: e.resource_conforms_to.into(),
+            unit_of_resource: e.unit_of_resource.into(),
+            unit_of_effort: e.unit_of_effort.into(),
+            image: e.image.into(),
+            note: e.note.into(),
+            substitutable: e.substitutable.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_*" bindings via Serde
+        }
+    }
+}
+
+//---------------- UPDATE ----------------
+
+/// Handles update operations by merging any newly provided fields
+impl Updateable<UpdateRequest> for EntryData {
+    fn update_with(&self, e: UpdateRequest) -> EntryData {
+        EntryData {
+            name: if !e.name.is_some() { self.name.to_owned() } else { e.name.to_owned().unwrap() },
+            resource_classified_as: if e.resource_classified_as.is_undefined() { self.resource_classified_as.to_owned() } else { e.resource_classified_as.to_owned().into() },
+            resource_conforms_to: if e.resource_conforms_to.is_undefined() { self.resource_conforms_to.to_owned() } else { e.resource_conforms_to.to_owned().into() },
+            unit_of_resource: if e.unit_of_resource.is_undefined() { self.unit_of_resource.to_owned() } else { e.unit_of_resource.to_owned().into() },
+            unit_of_effort: if e.unit_of_effort.is_undefined() { self.unit_of_effort.to_owned() } else { e.unit_of_effort.to_owned().into() },
+            image: if e.image.is_undefined() { self.image.to_owned() } else { e.image.to_owned().into() },
+            note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
+            substitutable: if let MaybeUndefined::Some(substitutable) = e.substitutable { substitutable } else { self.substitutable },
+        }
+    }
+}
@@ -0,0 +1,9 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const RECIPE_RESOURCE_ENTRY_TYPE: &str = "vf_recipe_resource";
+pub const RECIPE_RESOURCE_RECIPE_FLOWS_LINK_TAG: &str = "recipe_flows";
@@ -0,0 +1,13 @@
+/**
+ * RecipeResource query indexes for specification DNA
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_recipe_resource_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct RecipeResource {
+    recipe_flows: Local<recipe_flow, recipe_flow_resource>,
+}
@@ -72,6 +72,7 @@ fn construct_response<'a>(
             image: e.image.to_owned(),
             note: e.note.to_owned(),
             default_unit_of_effort: e.default_unit_of_effort.to_owned(),
+            allow_negative: e.allow_negative,
 
             // conforming_resources: conforming_resources.map(Cow::into_owned),
         }
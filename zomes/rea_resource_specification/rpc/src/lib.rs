@@ -7,11 +7,16 @@
  * @package Holo-REA
  */
 use holochain_serialized_bytes::prelude::*;
-use serde_maybe_undefined::MaybeUndefined;
+use serde_maybe_undefined::{
+    MaybeUndefined,
+    default_true,
+};
 pub use vf_attributes_hdk::{
     RevisionHash, ByAddress, ByHeader,
     ResourceSpecificationAddress,
     EconomicResourceAddress,
+    RecipeFlowAddress,
+    ProcessAddress,
     ExternalURL,
     UnitId,
 };
@@ -44,6 +49,7 @@ pub struct Response {
     pub note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_unit_of_effort: Option<UnitId>,
+    pub allow_negative: bool,
 }
 
 /// I/O struct to describe what is returned outside the gateway.
@@ -70,6 +76,8 @@ pub struct CreateRequest {
     pub note: MaybeUndefined<String>,
     #[serde(default)]
     pub default_unit_of_effort: MaybeUndefined<UnitId>,
+    #[serde(default = "default_true")]
+    pub allow_negative: MaybeUndefined<bool>,
 }
 
 impl<'a> CreateRequest {
@@ -92,6 +100,8 @@ pub struct UpdateRequest {
     pub note: MaybeUndefined<String>,
     #[serde(default)]
     pub default_unit_of_effort: MaybeUndefined<UnitId>,
+    #[serde(default)]
+    pub allow_negative: MaybeUndefined<bool>,
 }
 
 impl<'a> UpdateRequest {
@@ -108,4 +118,9 @@ impl<'a> UpdateRequest {
 #[serde(rename_all = "camelCase")]
 pub struct QueryParams {
     pub conforming_resources: Option<EconomicResourceAddress>,
+    pub recipe_flows: Option<RecipeFlowAddress>,
+    #[serde(default)]
+    pub flows_into: Option<ProcessAddress>,
+    #[serde(default)]
+    pub flows_out_of: Option<ProcessAddress>,
 }
@@ -9,6 +9,7 @@
 use hdk::prelude::*;
 
 use hdk_records::{
+    MaybeUndefined,
     record_interface::Updateable,
     generate_record_entry,
 };
@@ -28,6 +29,13 @@ pub struct EntryData {
     pub image: Option<ExternalURL>,
     pub note: Option<String>,
     pub default_unit_of_effort: Option<UnitId>,
+    #[serde(default = "default_allow_negative")]
+    pub allow_negative: bool,
+}
+
+// :NOTE: specifications recorded prior to the negative balance policy retain their original behaviour
+fn default_allow_negative() -> bool {
+    true
 }
 
 generate_record_entry!(EntryData, ResourceSpecificationAddress, EntryStorage);
@@ -42,6 +50,7 @@ impl From<CreateRequest> for EntryData {
             image: e.image.into(),
             note: e.note.into(),
             default_unit_of_effort: e.default_unit_of_effort.into(),
+            allow_negative: e.allow_negative.to_option().unwrap(),  // :NOTE: unsafe, would crash if not for "default_*" bindings via Serde
         }
     }
 }
@@ -56,6 +65,7 @@ impl Updateable<UpdateRequest> for EntryData {
             image: if e.image.is_undefined() { self.image.to_owned() } else { e.image.to_owned().into() },
             note: if e.note.is_undefined() { self.note.to_owned() } else { e.note.to_owned().into() },
             default_unit_of_effort: if e.default_unit_of_effort.is_undefined() { self.default_unit_of_effort.to_owned() } else { e.default_unit_of_effort.to_owned().into() },
+            allow_negative: if let MaybeUndefined::Some(allow_negative) = e.allow_negative { allow_negative } else { self.allow_negative },
         }
     }
 }
@@ -0,0 +1,18 @@
+/**
+ * Resource specification query indexes for observation DNA
+ *
+ * Tracks the observed & planned flows referencing specifications held in
+ * the specification DNA, so that Processes can be connected to one another.
+ *
+ * @package Holo-REA
+ */
+use hdk_semantic_indexes_zome_derive::index_zome;
+use hc_zome_rea_resource_specification_rpc::*;
+use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query struct
+
+#[index_zome]
+struct ResourceSpecification {
+    // internal indexes (not part of REA spec)
+    flows_into: Local<process, input_specifications>,
+    flows_out_of: Local<process, output_specifications>,
+}
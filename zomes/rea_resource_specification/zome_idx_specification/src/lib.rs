@@ -11,4 +11,5 @@ use hdk_semantic_indexes_zome_lib::ByAddress; // disambiguate from record query
 #[index_zome]
 struct ResourceSpecification {
     conforming_resources: Remote<economic_resource, conforms_to>,
+    recipe_flows: Local<recipe_flow, resource_conforms_to>,
 }
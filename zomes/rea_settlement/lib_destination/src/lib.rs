@@ -0,0 +1,85 @@
+/**
+ * Holo-REA settlement zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Settlement` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * Contains functionality for the "destination" side of an "indirect remote index" pair
+ * (@see `hdk_records` README).
+ *
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_settlement_storage::*;
+use hc_zome_rea_settlement_rpc::*;
+use hc_zome_rea_settlement_lib::construct_response;
+
+pub fn handle_create_settlement<S>(entry_def_id: S, settlement: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision_id, settlement_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, settlement.to_owned())?;
+
+    // link entries in the local DNA
+    create_index!(Local(settlement.settled_by(settlement.get_settled_by()), economic_event.settles(&settlement_address)))?;
+
+    // :TODO: figure out if necessary/desirable to do bidirectional bridging between observation and other planning DNAs
+
+    construct_response(&settlement_address, &revision_id, &entry_resp)
+}
+
+pub fn handle_get_settlement<S>(entry_def_id: S, address: SettlementAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry)
+}
+
+pub fn handle_update_settlement<S>(entry_def_id: S, settlement: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision_id, base_address, new_entry, prev_entry): (_, SettlementAddress, EntryData, EntryData) = update_record(&entry_def_id, &settlement.get_revision_id(), settlement.to_owned())?;
+
+    if new_entry.settled_by != prev_entry.settled_by {
+        update_index!(Local(
+            settlement
+                .settled_by(&vec![new_entry.settled_by.clone()])
+                .not(&vec![prev_entry.settled_by]),
+            economic_event.settles(&base_address)
+        ))?;
+    }
+
+    construct_response(&base_address, &revision_id, &new_entry)
+}
+
+pub fn handle_delete_settlement(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    // read any referencing indexes
+    let (base_address, settlement) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // handle link fields
+    update_index!(Local(settlement.settled_by.not(&vec![settlement.settled_by]), economic_event.settles(&base_address)))?;
+
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Properties accessor for zome config.
+fn read_economic_event_index_zome(conf: DnaConfigSliceObservation) -> Option<String> {
+    Some(conf.settlement.economic_event_index_zome)
+}
+
+/// Properties accessor for zome config.
+fn read_settlement_index_zome(conf: DnaConfigSliceObservation) -> Option<String> {
+    Some(conf.settlement.index_zome)
+}
@@ -0,0 +1,112 @@
+/**
+ * Holo-REA settlement zome library API
+ *
+ * Contains helper methods that can be used to manipulate `Settlement` data
+ * structures in either the local Holochain zome, or a separate DNA-local zome.
+ *
+ * Contains functionality for the "origin" side of an "indirect remote index" pair
+ * (@see `hdk_records` README).
+
+ * @package Holo-REA
+ */
+use paste::paste;
+use hdk_records::{
+    RecordAPIResult, OtherCellResult,
+    records::{
+        create_record,
+        read_record_entry,
+        read_record_entry_by_header,
+        update_record,
+        delete_record,
+    },
+    rpc::call_zome_method,
+};
+use hdk_semantic_indexes_client_lib::*;
+
+use hc_zome_rea_settlement_storage_consts::*;
+use hc_zome_rea_settlement_storage::*;
+use hc_zome_rea_settlement_rpc::*;
+use hc_zome_rea_settlement_lib::construct_response;
+
+pub fn handle_create_settlement<S>(entry_def_id: S, settlement: CreateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision_id, settlement_address, entry_resp): (_,_, EntryData) = create_record(&entry_def_id, settlement.to_owned())?;
+
+    // link entries in the local DNA
+    create_index!(Local(settlement.settles(settlement.get_settles()), claim.settled_by(&settlement_address)))?;
+
+    // update in the associated foreign DNA as well
+    let _pingback: OtherCellResult<ResponseData> = call_zome_method(
+        settlement.get_settled_by(),
+        &REPLICATE_CREATE_API_METHOD,
+        CreateParams { settlement: settlement.to_owned() },
+    );
+    // :TODO: report any error
+
+    construct_response(&settlement_address, &revision_id, &entry_resp)
+}
+
+pub fn handle_get_settlement<S>(entry_def_id: S, address: SettlementAddress) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision, base_address, entry) = read_record_entry::<EntryData, EntryStorage, _,_>(&entry_def_id, address.as_ref())?;
+    construct_response(&base_address, &revision, &entry)
+}
+
+pub fn handle_update_settlement<S>(entry_def_id: S, settlement: UpdateRequest) -> RecordAPIResult<ResponseData>
+    where S: AsRef<str>
+{
+    let (revision_id, base_address, new_entry, prev_entry): (_, SettlementAddress, EntryData, EntryData) = update_record(&entry_def_id, &settlement.get_revision_id(), settlement.to_owned())?;
+
+    // update claim indexes in local DNA
+    if new_entry.settles != prev_entry.settles {
+        update_index!(Local(
+            settlement
+                .settles(&vec![new_entry.settles.clone()])
+                .not(&vec![prev_entry.settles]),
+            claim.settled_by(&base_address)
+        ))?;
+    }
+
+    // update settlement records in remote DNA (and by proxy, event indexes in remote DNA)
+    if new_entry.settled_by != prev_entry.settled_by {
+        let _pingback: OtherCellResult<ResponseData> = call_zome_method(
+            // :TODO: update to intelligently call remote DNAs if new & old target record are not in same network
+            &prev_entry.settled_by,
+            &REPLICATE_UPDATE_API_METHOD,
+            UpdateParams { settlement: settlement.to_owned() },
+        );
+        // :TODO: report any error
+    }
+
+    construct_response(&base_address, &revision_id, &new_entry)
+}
+
+pub fn handle_delete_settlement(revision_id: RevisionHash) -> RecordAPIResult<bool>
+{
+    let (base_address, entry) = read_record_entry_by_header::<EntryData, EntryStorage, _>(&revision_id)?;
+
+    // update claim indexes in local DNA
+    update_index!(Local(settlement.settles.not(&vec![entry.settles]), claim.settled_by(&base_address)))?;
+
+    // update settlement records in remote DNA (and by proxy, event indexes in remote DNA)
+    let _pingback: OtherCellResult<ResponseData> = call_zome_method(
+        &entry.settled_by,
+        &REPLICATE_DELETE_API_METHOD,
+        ByHeader { address: revision_id.to_owned() },
+    );
+    // :TODO: report any error
+
+    delete_record::<EntryStorage, _>(&revision_id)
+}
+
+/// Properties accessor for zome config.
+fn read_claim_index_zome(conf: DnaConfigSlicePlanning) -> Option<String> {
+    Some(conf.settlement.claim_index_zome)
+}
+
+/// Properties accessor for zome config.
+fn read_settlement_index_zome(conf: DnaConfigSlicePlanning) -> Option<String> {
+    Some(conf.settlement.index_zome)
+}
@@ -0,0 +1,14 @@
+/**
+ * Storage constants for zome entry & link type identifiers
+ *
+ * Used by modules interfacing with the underlying Holochain storage system directly.
+ *
+ * @package Holo-REA
+ */
+pub const SETTLEMENT_ENTRY_TYPE: &str = "vf_settlement";
+pub const SETTLEMENT_SETTLES_LINK_TAG: &str = "settles";
+pub const SETTLEMENT_SETTLEDBY_LINK_TAG: &str = "settled_by";
+
+pub const REPLICATE_CREATE_API_METHOD: &str = "create_settlement";
+pub const REPLICATE_UPDATE_API_METHOD: &str = "update_settlement";
+pub const REPLICATE_DELETE_API_METHOD: &str = "delete_settlement";
@@ -0,0 +1,50 @@
+/**
+ * Holo-REA settlement remote index zome API definition
+ *
+ * Manages indexes for querying `EconomicEvents` against remote `Settlements`.
+ *
+ * Defines the top-level zome configuration needed by Holochain's build system
+ * to bundle the app. This basically involves wiring up the helper methods from the
+ * related `_lib` module into a packaged zome WASM binary.
+ *
+ * @package Holo-REA
+ */
+use hdk::prelude::*;
+
+use hc_zome_rea_settlement_lib_destination::*;
+use hc_zome_rea_settlement_rpc::*;
+use hc_zome_rea_settlement_storage_consts::*;
+
+#[hdk_extern]
+fn entry_defs(_: ()) -> ExternResult<EntryDefsCallbackResult> {
+    Ok(EntryDefsCallbackResult::from(vec![
+        PathEntry::entry_def(),
+        EntryDef {
+            id: SETTLEMENT_ENTRY_TYPE.into(),
+            visibility: EntryVisibility::Public,
+            crdt_type: CrdtType,
+            required_validations: 1.into(),
+            required_validation_type: RequiredValidationType::default(),
+        }
+    ]))
+}
+
+#[hdk_extern]
+fn settlement_created(CreateParams { settlement }: CreateParams) -> ExternResult<ResponseData> {
+    Ok(handle_create_settlement(SETTLEMENT_ENTRY_TYPE, settlement)?)
+}
+
+#[hdk_extern]
+fn get_settlement(ByAddress { address }: ByAddress<SettlementAddress>) -> ExternResult<ResponseData> {
+    Ok(handle_get_settlement(SETTLEMENT_ENTRY_TYPE, address)?)
+}
+
+#[hdk_extern]
+fn settlement_updated(UpdateParams { settlement }: UpdateParams) -> ExternResult<ResponseData> {
+    Ok(handle_update_settlement(SETTLEMENT_ENTRY_TYPE, settlement)?)
+}
+
+#[hdk_extern]
+fn settlement_deleted(ByHeader { address }: ByHeader) -> ExternResult<bool> {
+    Ok(handle_delete_settlement(address)?)
+}